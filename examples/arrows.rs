@@ -0,0 +1,22 @@
+use ndarray::Array3;
+use vista::{Arrows, DisplayExt, Magnitude};
+
+fn main() {
+    let vortex = Array3::from_shape_fn((9, 15, 2), |(i, j, k)| {
+        let (x, y) = (j as f64 - 7.0, 4.0 - i as f64);
+        if k == 0 { -y } else { x }
+    });
+    let shear = Array3::from_shape_fn((9, 15, 2), |(i, _, k)| if k == 0 { (4.0 - i as f64) * 0.5 } else { 0.0 });
+
+    println!("Coloured by magnitude");
+    println!("{}\n", vortex.display_with(Arrows::new()));
+
+    println!("Double arrows for strong vectors");
+    println!("{}\n", vortex.display_with(Arrows::new().with_magnitude(Magnitude::Weight)));
+
+    println!("Averaged over 2x2 blocks, side by side");
+    println!(
+        "{}",
+        [&vortex, &shear].display_with(Arrows::new().with_step(2).with_magnitude(Magnitude::Weight))
+    );
+}
@@ -0,0 +1,32 @@
+use ndarray::Array4;
+use vista::{AxisMap, DisplayExt, Separated, SliceHeaders};
+
+fn main() {
+    // (time, channel, y, x)
+    let a = Array4::from_shape_fn((2, 3, 2, 2), |(t, c, y, x)| t * 1000 + c * 100 + y * 10 + x);
+    let headers = SliceHeaders::named(["t", "c", "y", "x"]);
+
+    println!("Channels outermost");
+    println!(
+        "{}\n",
+        a.display::<Separated>()
+            .with_axis_map(AxisMap::new(2, 3).with_pages(vec![1, 0]))
+            .with_slice_headers(headers.clone())
+    );
+
+    println!("Time as columns");
+    println!(
+        "{}\n",
+        a.display::<Separated>()
+            .with_axis_map(AxisMap::new(1, 0))
+            .with_slice_headers(headers.clone())
+    );
+
+    println!("Time and channel grouped");
+    println!(
+        "{}",
+        a.display::<Separated>()
+            .with_axis_map(AxisMap::new(2, 3).with_groups(vec![vec![0, 1]]))
+            .with_slice_headers(headers)
+    );
+}
@@ -0,0 +1,26 @@
+use ndarray::{Array2, array};
+use vista::{BoxPlot, DisplayExt, Groups};
+
+fn main() {
+    let measurements = Array2::from_shape_fn((50, 3), |(i, j)| {
+        let spread = (i * 7919 % 50) as f64 / 50.0;
+        match j {
+            0 => 10.0 + 8.0 * spread,
+            1 => 14.0 + 3.0 * spread * spread,
+            _ => 20.0 + 2.0 * spread + if i == 7 { 15.0 } else { 0.0 },
+        }
+    });
+
+    println!("Per column");
+    println!(
+        "{}\n",
+        measurements.display_with(BoxPlot::new(50).with_labels(["width", "height", "depth"]))
+    );
+
+    let runs = array![[1.0, 2.0, 2.5, 3.0, 4.0, 9.0], [2.0, 2.2, 2.4, 2.6, 2.8, 3.0]];
+    println!("Per row, side by side");
+    println!(
+        "{}",
+        [&runs, &(&runs * 1.5)].display_with(BoxPlot::new(40).with_groups(Groups::Rows).with_precision(1))
+    );
+}
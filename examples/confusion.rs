@@ -0,0 +1,19 @@
+use ndarray::arr2;
+use vista::{Confusion, DisplayExt, Normalisation};
+
+fn main() {
+    let counts = arr2(&[[12, 3, 0], [2, 15, 1], [0, 4, 9]]);
+
+    println!("Counts");
+    println!("{}\n", counts.display::<Confusion>());
+
+    println!("Labelled, with row percentages");
+    println!(
+        "{}",
+        counts.display_with(
+            Confusion::new()
+                .with_labels(["cat", "dog", "bird"])
+                .with_normalisation(Normalisation::Rows)
+        )
+    );
+}
@@ -0,0 +1,22 @@
+use ndarray::Array2;
+use vista::{Contour, DisplayExt};
+
+fn main() {
+    let dipole = Array2::from_shape_fn((15, 30), |(i, j)| {
+        let (x, y) = (j as f64 / 29.0 * 4.0 - 2.0, i as f64 / 14.0 * 4.0 - 2.0);
+        (-(x - 0.7).powi(2) - y.powi(2)).exp() - 0.8 * (-(x + 0.9).powi(2) - (y - 0.6).powi(2)).exp()
+    });
+
+    println!("Automatic levels, labelled");
+    println!("{}\n", dipole.display_with(Contour::new(6).with_labels(true)));
+
+    println!("Resampled to 50x16");
+    println!("{}\n", dipole.display_with(Contour::new(4).with_size(50, 16)));
+
+    let bowl = Array2::from_shape_fn((9, 9), |(i, j)| ((i as f64 - 4.0).powi(2) + (j as f64 - 4.0).powi(2)).sqrt());
+    println!("Chosen levels, side by side");
+    println!(
+        "{}",
+        [&bowl, &(&bowl * 0.5)].display_with(Contour::new(3).with_levels([1.0, 2.0, 3.0]).with_labels(true))
+    );
+}
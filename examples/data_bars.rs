@@ -0,0 +1,21 @@
+use ndarray::{arr1, arr2};
+use vista::{BarPlacement, DataBars, DisplayExt, Separated};
+
+fn main() {
+    let scores = arr1(&[3.0, 7.5, 1.0, 10.0, 0.0]);
+    let changes = arr2(&[[-4, 2, 8], [1, -1, 0], [6, 3, -2]]);
+
+    println!("Beside");
+    println!("{}\n", scores.display::<Separated>().with_data_bars(DataBars::default()));
+
+    println!("Negative values");
+    println!("{}\n", changes.display::<Separated>().with_data_bars(DataBars::new(6)));
+
+    println!("Beneath");
+    println!(
+        "{}",
+        changes
+            .display::<Separated>()
+            .with_data_bars(DataBars::new(6).with_placement(BarPlacement::Beneath))
+    );
+}
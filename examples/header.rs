@@ -0,0 +1,17 @@
+use ndarray::{Array2, s};
+use vista::{DisplayExt, Separated};
+
+fn main() {
+    let a = Array2::from_shape_fn((3, 4), |(i, j)| i * 10 + j);
+    let b = a.t();
+    let c = a.slice(s![.., ..;2]);
+
+    println!("Owned");
+    println!("{}\n", a.display::<Separated>().with_header(true));
+
+    println!("Transposed view");
+    println!("{}\n", b.display::<Separated>().with_header(true));
+
+    println!("Strided views side by side");
+    println!("{}", [&c, &c].display::<Separated>().with_header(true));
+}
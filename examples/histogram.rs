@@ -0,0 +1,26 @@
+use ndarray::Array1;
+use vista::{DisplayExt, Histogram, Orientation};
+
+fn main() {
+    let triangular = Array1::from_shape_fn(1000, |i| {
+        let u = (i * 7919 % 1000) as f64 / 1000.0;
+        let v = (i * 104729 % 997) as f64 / 997.0;
+        (u + v) * 5.0
+    });
+    let uniform = Array1::from_shape_fn(1000, |i| (i * 7919 % 1000) as f64 / 100.0);
+
+    println!("Horizontal");
+    println!("{}\n", triangular.display_with(Histogram::new(8).with_length(30)));
+
+    println!("Side by side, with logarithmic bars");
+    println!(
+        "{}\n",
+        [&triangular, &uniform].display_with(Histogram::new(5).with_length(15).with_log(true))
+    );
+
+    println!("Vertical");
+    println!(
+        "{}",
+        [&triangular, &uniform].display_with(Histogram::new(20).with_orientation(Orientation::Vertical).with_length(6))
+    );
+}
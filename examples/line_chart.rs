@@ -0,0 +1,16 @@
+use ndarray::Array1;
+use vista::{DisplayExt, LineChart};
+
+fn main() {
+    let sin = Array1::from_shape_fn(100, |i| (i as f64 / 10.0).sin());
+    let cos = Array1::from_shape_fn(100, |i| (i as f64 / 15.0).cos() * 0.5);
+
+    println!("Single series");
+    println!("{}\n", sin.display_with(LineChart::new(50, 10)));
+
+    println!("Overlaid series");
+    println!(
+        "{}",
+        [&sin, &cos].display_with(LineChart::new(50, 12).with_labels(["sin", "cos"]))
+    );
+}
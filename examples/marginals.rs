@@ -0,0 +1,22 @@
+use ndarray::{Array2, arr2};
+use vista::{CommaSeparated, DisplayExt, Marginals, Reduction, Separated};
+
+fn main() {
+    let counts = arr2(&[[12, 3, 0], [2, 15, 1], [0, 4, 9]]);
+    let costs = Array2::from_shape_fn((3, 4), |(i, j)| (i as f64 + 1.0) * 0.5 + j as f64);
+
+    println!("Totals");
+    println!("{}\n", counts.display::<Separated>().with_marginals(Marginals::default()));
+
+    println!("Means");
+    println!(
+        "{}\n",
+        costs
+            .display::<CommaSeparated>()
+            .with_marginals(Marginals::new(Reduction::Mean).with_precision(3))
+    );
+
+    println!("Root mean square");
+    let rms = Reduction::Custom(|values| (values.iter().map(|v| v * v).sum::<f64>() / values.len() as f64).sqrt());
+    println!("{}", costs.display::<Separated>().with_marginals(Marginals::new(rms)));
+}
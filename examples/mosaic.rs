@@ -0,0 +1,16 @@
+use ndarray::{Array3, Array4};
+use vista::{DisplayExt, DoubleJoined, Mosaic, Separated};
+
+fn main() {
+    let a = Array4::from_shape_fn((3, 2, 4, 4), |(i, j, y, x)| u8::from((i + j + y + x) % 3 == 0));
+    let b = Array3::from_shape_fn((5, 2, 3), |(i, y, x)| i * 100 + y * 10 + x);
+
+    println!("Labelled 4D mosaic");
+    println!(
+        "{}\n",
+        a.display::<DoubleJoined>().with_mosaic(Mosaic::new().with_labels(true))
+    );
+
+    println!("Wrapped 3D mosaic");
+    println!("{}", b.display::<Separated>().with_mosaic(Mosaic::new().with_wrap(3)));
+}
@@ -0,0 +1,25 @@
+use ndarray::Array3;
+use vista::{DisplayExt, DoubleJoined, OrthoSlices, Rulers, Separated};
+
+fn main() {
+    let volume = Array3::from_shape_fn((4, 5, 6), |(z, y, x)| 100 * z + 10 * y + x);
+    let ball = Array3::from_shape_fn((8, 8, 8), |(z, y, x)| {
+        let offset = |index: usize| index as f64 - 3.5;
+        u8::from(offset(x).powi(2) + offset(y).powi(2) + offset(z).powi(2) <= 10.0)
+    });
+
+    println!("Mid-planes");
+    println!("{}\n", volume.display::<Separated>().with_ortho_slices(OrthoSlices::new()));
+
+    println!("Planes through a chosen point, with rulers");
+    println!(
+        "{}\n",
+        volume
+            .display::<Separated>()
+            .with_ortho_slices(OrthoSlices::new().with_point([0, 4, 1]))
+            .with_rulers(Rulers::new())
+    );
+
+    println!("Mid-planes of a ball");
+    println!("{}", ball.display::<DoubleJoined>().with_ortho_slices(OrthoSlices::new()));
+}
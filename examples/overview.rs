@@ -0,0 +1,20 @@
+use ndarray::Array2;
+use vista::{DisplayExt, DoubleJoined, Overview, Reduction, Rulers, Separated};
+
+fn main() {
+    let a = Array2::from_shape_fn((400, 300), |(i, j)| {
+        (f64::from(i as u32) / 40.0).sin() * (f64::from(j as u32) / 30.0).cos()
+    });
+    let b = Array2::from_shape_fn((256, 512), |(i, j)| u8::from((i / 32 + j / 32) % 2 == 0 && (i * j) % 7 == 0));
+
+    println!("Mean of blocks");
+    println!("{}\n", a.display::<Separated>().with_overview(Overview::new(10, 8)));
+
+    println!("Any non-zero in blocks");
+    println!(
+        "{}",
+        b.display::<DoubleJoined>()
+            .with_overview(Overview::new(32, 16).with_reduction(Reduction::Any))
+            .with_rulers(Rulers::every(128))
+    );
+}
@@ -0,0 +1,22 @@
+use ndarray::{Array2, Array3, s};
+use vista::{DisplayExt, DoubleJoined, Rulers, Separated};
+
+fn main() {
+    let a = Array2::from_shape_fn((12, 16), |(i, j)| (i * j) % 10);
+    let b = Array3::from_shape_fn((2, 3, 4), |(i, j, k)| i * 100 + j * 10 + k);
+
+    println!("Every index");
+    println!(
+        "{}\n",
+        a.slice(s![..6, ..10]).display::<Separated>().with_rulers(Rulers::new())
+    );
+
+    println!("Crowded columns, thinned to every 2nd index");
+    println!("{}\n", a.display::<Separated>().with_rulers(Rulers::new()));
+
+    println!("Every 5th index");
+    println!("{}\n", a.display::<DoubleJoined>().with_rulers(Rulers::every(5)));
+
+    println!("Block headers");
+    println!("{}", b.display::<Separated>().with_rulers(Rulers::new()));
+}
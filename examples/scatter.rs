@@ -0,0 +1,37 @@
+use ndarray::Array2;
+use vista::{DisplayExt, PointEncoding, Scatter};
+
+fn main() {
+    // A spiral, with the angle of each point in the third column
+    let spiral = Array2::from_shape_fn((200, 3), |(i, j)| {
+        let t = i as f64 / 200.0 * std::f64::consts::TAU;
+        match j {
+            0 => t.cos() * (1.0 + t / 6.0),
+            1 => t.sin() * (1.0 + t / 6.0),
+            _ => t,
+        }
+    });
+    let cloud = Array2::from_shape_fn((2000, 2), |(i, j)| {
+        let a = (i * 7919 % 1000) as f64 / 1000.0;
+        let b = (i * 104729 % 997) as f64 / 997.0;
+        if j == 0 { a * b } else { b }
+    });
+
+    println!("Points");
+    println!("{}\n", spiral.display_with(Scatter::new(40, 12)));
+
+    println!("Coloured by the third column");
+    println!(
+        "{}\n",
+        spiral.display_with(Scatter::new(40, 12).with_encoding(PointEncoding::Colour))
+    );
+
+    println!("Sized by the third column");
+    println!(
+        "{}\n",
+        spiral.display_with(Scatter::new(40, 12).with_encoding(PointEncoding::Size))
+    );
+
+    println!("Shaded by density");
+    println!("{}", cloud.display_with(Scatter::new(30, 8).with_density(10)));
+}
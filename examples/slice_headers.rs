@@ -0,0 +1,16 @@
+use ndarray::Array4;
+use vista::{DisplayExt, Separated, SliceHeaders};
+
+fn main() {
+    let a = Array4::from_shape_fn((2, 2, 2, 3), |(t, z, y, x)| t * 1000 + z * 100 + y * 10 + x);
+
+    println!("Index");
+    println!("{}\n", a.display::<Separated>().with_slice_headers(SliceHeaders::new()));
+
+    println!("Named");
+    println!(
+        "{}",
+        a.display::<Separated>()
+            .with_slice_headers(SliceHeaders::named(["t", "z", "y", "x"]))
+    );
+}
@@ -0,0 +1,26 @@
+use ndarray::{Array1, Array2, arr1};
+use vista::{DisplayExt, Sparkline};
+
+fn main() {
+    let pulse = arr1(&[1.0, 2.0, 3.0, 5.0, 8.0, 5.0, 3.0, 2.0, 1.0]);
+    let wave = Array1::from_shape_fn(500, |i| (i as f64 / 20.0).sin());
+    let rows = Array2::from_shape_fn((3, 12), |(i, j)| ((i + 1) * j) % 7);
+
+    println!("Series");
+    println!("{}\n", pulse.display::<Sparkline>());
+
+    println!("Resampled to 40 glyphs");
+    println!(
+        "{}\n",
+        wave.display_with(Sparkline::new().with_width(40).with_annotations(true))
+    );
+
+    println!("One sparkline per row");
+    println!(
+        "{}\n",
+        rows.display_with(Sparkline::new().with_labels(true).with_annotations(true))
+    );
+
+    println!("Rows of several arrays");
+    println!("{}", [&rows, &(&rows * 2)].display_with(Sparkline::new().with_labels(true)));
+}
@@ -0,0 +1,27 @@
+use ndarray::{Array1, Array2, Array3};
+use vista::{DisplayExt, EntryOrder, Sparse};
+
+fn main() {
+    let diagonal = Array2::from_shape_fn((5, 5), |(row, col)| if row == col { row as f64 + 0.5 } else { 0.0 });
+    let band = Array2::from_shape_fn((4, 6), |(row, col)| if col == row + 1 { -1.0 } else { 0.0 });
+    let noisy = Array1::from_vec(vec![0.0, 1e-9, -3.0, 0.0, 2.5, -1e-12, 0.0, 7.0]);
+    let volume = Array3::from_shape_fn(
+        (4, 4, 4),
+        |(z, y, x)| if (x + y + z) % 5 == 0 { (x * y + z) as i32 - 4 } else { 0 },
+    );
+
+    println!("Diagonal matrix");
+    println!("{}\n", diagonal.display_with(Sparse::new()));
+
+    println!("Vector, ignoring entries within 1e-6 of zero, largest first");
+    println!(
+        "{}\n",
+        noisy.display_with(Sparse::new().with_tolerance(1e-6).with_order(EntryOrder::Magnitude))
+    );
+
+    println!("Volume, limited to five entries");
+    println!("{}\n", volume.display_with(Sparse::new().with_limit(5)));
+
+    println!("Side by side");
+    println!("{}", [&diagonal, &band].display_with(Sparse::new().with_precision(0)));
+}
@@ -0,0 +1,51 @@
+use sprs::{CsMat, CsVec, TriMat};
+use vista::{DisplaySparseExt, Separated, Sparse, Spy, Window};
+
+fn main() {
+    // Tridiagonal matrix with a million rows, assembled in compressed sparse row form
+    let n = 1_000_000;
+    let mut triplets = TriMat::new((n, n));
+    for i in 0..n {
+        triplets.add_triplet(i, i, 2.0);
+        if i + 1 < n {
+            triplets.add_triplet(i, i + 1, -1.0);
+            triplets.add_triplet(i + 1, i, -1.0);
+        }
+    }
+    let tridiagonal: CsMat<f64> = triplets.to_csr();
+
+    println!("Corner of a tridiagonal matrix with a million rows");
+    println!(
+        "{}\n",
+        tridiagonal.display::<Separated>().with_window(Window::new([0..6, 0..8]))
+    );
+
+    println!("Without a window, the first 20 rows and columns");
+    println!("{}\n", tridiagonal.display::<Separated>());
+
+    println!("Same corner with implicit zeros left blank");
+    println!(
+        "{}\n",
+        tridiagonal
+            .display::<Separated>()
+            .with_window(Window::new([0..6, 0..8]))
+            .with_implicit(' ')
+    );
+
+    let small = CsMat::new_csc((4, 5), vec![0, 1, 1, 3, 3, 4], vec![0, 1, 3, 2], vec![1.5, -2.0, 4.0, 0.5]);
+    let doubled = small.map(|value| value * 2.0);
+    let identity: CsMat<f64> = CsMat::eye(5).to_csc();
+
+    println!("Compressed sparse column matrix, listed entry by entry");
+    println!("{}\n", small.display::<Sparse>());
+
+    println!("Side by side");
+    println!("{}\n", [&small, &doubled].display::<Separated>());
+
+    println!("Pattern of an identity matrix");
+    println!("{}\n", identity.display::<Spy>());
+
+    println!("Sparse vector");
+    let vector = CsVec::new(10, vec![1, 4, 9], vec![3, -1, 7]);
+    println!("{}", vector.display::<Separated>());
+}
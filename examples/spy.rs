@@ -0,0 +1,37 @@
+use ndarray::Array2;
+use vista::{DisplayExt, Spy};
+
+fn main() {
+    // Stiffness-like matrix of a 1-D mesh with coupling to the second neighbour
+    let banded = Array2::from_shape_fn((12, 12), |(row, col)| match row.abs_diff(col) {
+        0 => 4.0,
+        1 => -1.0,
+        2 if row < col => -0.5,
+        _ => 0.0,
+    });
+    // Arrowhead matrix, dense in its first row and column
+    let arrow = Array2::from_shape_fn(
+        (12, 12),
+        |(row, col)| if row == 0 || col == 0 || row == col { 1.0 } else { 0.0 },
+    );
+    // 5-point Laplacian of a 15 x 15 grid, with 225 unknowns
+    let side = 15;
+    let laplacian = Array2::from_shape_fn((side * side, side * side), |(row, col)| {
+        let (r, c) = (row / side, row % side);
+        let (s, t) = (col / side, col % side);
+        match r.abs_diff(s) + c.abs_diff(t) {
+            0 => 4,
+            1 => -1,
+            _ => 0,
+        }
+    });
+
+    println!("Banded matrix");
+    println!("{}\n", banded.display_with(Spy::new()));
+
+    println!("Laplacian, downsampled to 20 characters across");
+    println!("{}\n", laplacian.display_with(Spy::new().with_size(20, 10)));
+
+    println!("Side by side, ignoring entries of at most 0.5");
+    println!("{}", [&banded, &arrow].display_with(Spy::new().with_tolerance(0.5)));
+}
@@ -0,0 +1,14 @@
+use ndarray::{Array2, arr2};
+use vista::{DisplayExt, Separated};
+
+fn main() {
+    let a = arr2(&[[1.5, f64::NAN, 3.0], [0.0, f64::INFINITY, -2.25]]);
+    let b = Array2::from_shape_fn((3, 4), |(i, j)| i * 4 + j);
+    let c = Array2::from_shape_fn((3, 4), |(i, j)| i * j);
+
+    println!("Special values");
+    println!("{}\n", a.display::<Separated>().with_stats(true));
+
+    println!("Side by side");
+    println!("{}", [&b, &c].display::<Separated>().with_stats(true));
+}
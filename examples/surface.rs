@@ -0,0 +1,30 @@
+use ndarray::Array2;
+use vista::{DisplayExt, Projection, Surface};
+
+fn main() {
+    let hill = Array2::from_shape_fn((25, 25), |(i, j)| {
+        let (x, y) = (j as f64 / 24.0 * 6.0 - 3.0, i as f64 / 24.0 * 6.0 - 3.0);
+        (-(x * x + y * y) / 2.0).exp() * 3.0
+    });
+    let ripples = Array2::from_shape_fn((30, 30), |(i, j)| (i as f64 / 4.0).sin() * (j as f64 / 4.0).cos());
+
+    println!("Isometric");
+    println!("{}\n", hill.display_with(Surface::new(60, 20)));
+
+    println!("Oblique, exaggerated");
+    println!(
+        "{}\n",
+        hill.display_with(
+            Surface::new(60, 20)
+                .with_projection(Projection::Oblique)
+                .with_view(0.0, 45.0)
+                .with_exaggeration(1.5)
+        )
+    );
+
+    println!("Turned and tilted, side by side");
+    println!(
+        "{}",
+        [&ripples, &(&ripples * 0.5)].display_with(Surface::new(40, 14).with_view(30.0, 35.0))
+    );
+}
@@ -0,0 +1,22 @@
+use ndarray::Array3;
+use vista::{DisplayExt, Shading, Voxels};
+
+fn main() {
+    let stairs = Array3::from_shape_fn((3, 3, 3), |(z, _, x)| x >= z);
+    let ball = Array3::from_shape_fn((6, 6, 6), |(z, y, x)| {
+        let offset = |index: usize| index as f64 - 2.5;
+        offset(x).powi(2) + offset(y).powi(2) + offset(z).powi(2) <= 7.0
+    });
+
+    println!("Stairs");
+    println!("{}\n", stairs.display_with(Voxels::new()));
+
+    println!("Stairs, turned twice and seen with the last axis up");
+    println!("{}\n", stairs.display_with(Voxels::new().with_turns(2).with_up(2)));
+
+    println!("Side by side, shaded by colour");
+    println!(
+        "{}",
+        [&ball, &stairs].display_with(Voxels::new().with_shading(Shading::Colour))
+    );
+}
@@ -0,0 +1,21 @@
+use ndarray::{Array2, Array3};
+use vista::{DisplayExt, Rulers, Separated, Window};
+
+fn main() {
+    let a = Array2::from_shape_fn((200, 100), |(i, j)| (i * j) % 97);
+    let b = Array3::from_shape_fn((5, 4, 4), |(i, y, x)| i * 100 + y * 10 + x);
+
+    println!("Interior region");
+    println!("{}\n", a.display::<Separated>().with_window(Window::new([100..110, 40..52])));
+
+    println!("Corner region with sparse rulers");
+    println!(
+        "{}\n",
+        a.display::<Separated>()
+            .with_window(Window::new([190..200, 0..12]))
+            .with_rulers(Rulers::every(5))
+    );
+
+    println!("3D region");
+    println!("{}", b.display::<Separated>().with_window(Window::new([3..5, 1..3])));
+}
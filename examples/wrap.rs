@@ -0,0 +1,16 @@
+use ndarray::Array2;
+use vista::{CommaSeparated, DisplayExt, Separated, Wrap};
+
+fn main() {
+    let a = Array2::from_shape_fn((3, 30), |(i, j)| i * 100 + j);
+    let b = Array2::from_shape_fn((3, 30), |(i, j)| (i + j) % 7);
+
+    println!("Single array");
+    println!("{}\n", a.display::<Separated>().with_wrap(Wrap::new(40)));
+
+    println!("Side by side");
+    println!("{}\n", [&a, &b].display::<CommaSeparated>().with_wrap(Wrap::new(60)));
+
+    println!("Detected width");
+    println!("{}", a.display::<Separated>().with_wrap(Wrap::detect()));
+}
@@ -5,13 +5,46 @@
 use ndarray::{ArrayBase, RawData};
 use std::marker::PhantomData;
 
-use crate::display_method::DisplayMethod;
+use crate::{
+    AxisMap, DataBars, Marginals, Mosaic, Numeric, OrthoSlices, Overview, Rulers, SliceHeaders, Window, Wrap,
+    display_method::DisplayMethod,
+};
 
-/// Display wrapper with a phantom type for the display method
+/// Display wrapper with a phantom type for the display method, and any settings of the method.
 #[non_exhaustive]
 pub struct DisplayArray<'a, T: RawData, D, M: DisplayMethod> {
     /// The array to be displayed.
     pub arrays: Vec<&'a ArrayBase<T, D>>,
+    /// Axes used for the rows, columns and pages of the display.
+    pub axis_map: Option<AxisMap>,
+    /// Bars drawn in proportion to the value of each element.
+    pub data_bars: Option<DataBars>,
+    /// Per-row and per-column reductions appended to each 2-D block.
+    pub marginals: Option<Marginals>,
+    /// Grid layout for the blocks of higher-dimensional arrays.
+    pub mosaic: Option<Mosaic>,
+    /// Orthogonal planes through a point of 3-D arrays, shown side by side.
+    pub ortho_slices: Option<OrthoSlices>,
+    /// Downsampling of large arrays by pooling blocks of elements.
+    pub overview: Option<Overview>,
+    /// Whether each array is headed by a description of its type, shape and memory layout.
+    pub header: bool,
+    /// Whether each numeric array is followed by summary statistics of its values.
+    pub stats: bool,
+    /// Index labels drawn around each displayed block.
+    pub rulers: Option<Rulers>,
+    /// Position headers placed above each 2-D block of higher-dimensional arrays.
+    pub slice_headers: Option<SliceHeaders>,
+    /// Region of the arrays to display.
+    pub window: Option<Window>,
+    /// Line width at which wide blocks are split into blocks of columns.
+    pub wrap: Option<Wrap>,
+    /// Conversion of elements to floating point values, captured by the options which need it.
+    pub numeric: Option<fn(&T::Elem) -> f64>,
+    /// Original index of the first element and original shape, when the arrays hold only a region of larger data.
+    pub region: Option<(Vec<usize>, Vec<usize>)>,
+    /// Settings of the display method, if given rather than left at their defaults.
+    pub method: Option<M>,
     /// Marker to hold the display method type.
     pub _phantom: PhantomData<M>,
 }
@@ -23,7 +56,157 @@ impl<'a, T: RawData, D, M: DisplayMethod> DisplayArray<'a, T, D, M> {
     pub const fn new(arrays: Vec<&'a ArrayBase<T, D>>) -> Self {
         DisplayArray {
             arrays,
+            axis_map: None,
+            data_bars: None,
+            marginals: None,
+            mosaic: None,
+            ortho_slices: None,
+            overview: None,
+            header: false,
+            stats: false,
+            rulers: None,
+            slice_headers: None,
+            window: None,
+            wrap: None,
+            numeric: None,
+            region: None,
+            method: None,
             _phantom: PhantomData,
         }
     }
+
+    /// Display the arrays with the given settings of the display method, rather than its defaults.
+    #[must_use]
+    #[inline]
+    pub fn with_method(mut self, method: M) -> Self {
+        self.method = Some(method);
+        self
+    }
+
+    /// Choose which axes are displayed as the rows, columns and pages.
+    ///
+    /// The arrays are traversed in the new order without copying. Arrays with fewer than two dimensions are unaffected.
+    #[must_use]
+    #[inline]
+    pub fn with_axis_map(mut self, axis_map: AxisMap) -> Self {
+        self.axis_map = Some(axis_map);
+        self
+    }
+
+    /// Tile the 2-D blocks of higher-dimensional arrays into a grid, rather than stacking them vertically.
+    #[must_use]
+    #[inline]
+    pub const fn with_mosaic(mut self, mosaic: Mosaic) -> Self {
+        self.mosaic = Some(mosaic);
+        self
+    }
+
+    /// Show the three orthogonal planes through a point of 3-D arrays side by side, in place of every 2-D block.
+    ///
+    /// Any axis mapping and mosaic are ignored, and crosshairs mark where the other planes cross each plane.
+    #[must_use]
+    #[inline]
+    pub const fn with_ortho_slices(mut self, ortho_slices: OrthoSlices) -> Self {
+        self.ortho_slices = Some(ortho_slices);
+        self
+    }
+
+    /// Head each array with a description of its type, shape, strides and memory layout.
+    #[must_use]
+    #[inline]
+    pub const fn with_header(mut self, header: bool) -> Self {
+        self.header = header;
+        self
+    }
+
+    /// Label the row and column indices of each displayed block.
+    #[must_use]
+    #[inline]
+    pub const fn with_rulers(mut self, rulers: Rulers) -> Self {
+        self.rulers = Some(rulers);
+        self
+    }
+
+    /// Head each 2-D block of higher-dimensional arrays with its position.
+    #[must_use]
+    #[inline]
+    pub fn with_slice_headers(mut self, slice_headers: SliceHeaders) -> Self {
+        self.slice_headers = Some(slice_headers);
+        self
+    }
+
+    /// Split blocks wider than the given line width into blocks of columns, each headed by the columns it holds.
+    #[must_use]
+    #[inline]
+    pub const fn with_wrap(mut self, wrap: Wrap) -> Self {
+        self.wrap = Some(wrap);
+        self
+    }
+
+    /// Display only the given region of the arrays, labelled with its original coordinates.
+    ///
+    /// Rulers are shown unless configured otherwise, and clipped edges are marked with ellipses.
+    #[must_use]
+    #[inline]
+    pub fn with_window(mut self, window: Window) -> Self {
+        self.window = Some(window);
+        self
+    }
+}
+
+impl<T: RawData, D, M: DisplayMethod> DisplayArray<'_, T, D, M>
+where
+    T::Elem: Numeric,
+{
+    /// Shrink each 2-D block to fit the given size by pooling blocks of elements, and report the pooling in a header.
+    #[must_use]
+    #[inline]
+    pub fn with_overview(mut self, overview: Overview) -> Self {
+        self.overview = Some(overview);
+        self.numeric = Some(T::Elem::to_f64);
+        self
+    }
+
+    /// Draw a bar in proportion to the value of each element, beside or beneath it.
+    ///
+    /// Bars are scaled to the range of each array, and those of negative values extend to the left of a zero line.
+    #[must_use]
+    #[inline]
+    pub fn with_data_bars(mut self, data_bars: DataBars) -> Self {
+        self.data_bars = Some(data_bars);
+        self.numeric = Some(T::Elem::to_f64);
+        self
+    }
+
+    /// Append a column of row reductions and a row of column reductions to each 2-D block, set apart from its body.
+    ///
+    /// The reductions are taken over the displayed elements, after any window or overview.
+    #[must_use]
+    #[inline]
+    pub fn with_marginals(mut self, marginals: Marginals) -> Self {
+        self.marginals = Some(marginals);
+        self.numeric = Some(T::Elem::to_f64);
+        self
+    }
+
+    /// Follow each array with its counts of all and finite values, mean, standard deviation, extremes and numbers of NaN,
+    /// infinite and zero values.
+    ///
+    /// The statistics are computed once over each whole array, regardless of any window or overview.
+    #[must_use]
+    #[inline]
+    pub fn with_stats(mut self, stats: bool) -> Self {
+        self.stats = stats;
+        self.numeric = Some(T::Elem::to_f64);
+        self
+    }
+}
+
+impl<T: RawData, D, M: DisplayMethod + Clone + Default> DisplayArray<'_, T, D, M> {
+    /// Settings of the display method: those given with `with_method`, or else its defaults.
+    #[must_use]
+    #[inline]
+    pub fn settings(&self) -> M {
+        self.method.clone().unwrap_or_default()
+    }
 }
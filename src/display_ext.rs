@@ -7,9 +7,17 @@ use ndarray::{ArrayBase, RawData};
 use crate::{DisplayArray, display_method::DisplayMethod};
 
 /// Common trait for displayable arrays.
+///
+/// `T` is the storage type of the arrays, such as `OwnedRepr<f64>` or `ViewRepr<&f64>`, and `D` is their dimension.
 pub trait DisplayExt<T: RawData, D> {
     /// Construct a `DisplayArray` type for the given data.
     fn display<M: DisplayMethod>(&self) -> DisplayArray<'_, T, D, M>;
+
+    /// Construct a `DisplayArray` type for the given data, using the given settings of the display method.
+    #[inline]
+    fn display_with<M: DisplayMethod>(&self, method: M) -> DisplayArray<'_, T, D, M> {
+        self.display().with_method(method)
+    }
 }
 
 impl<T: RawData, D> DisplayExt<T, D> for ArrayBase<T, D> {
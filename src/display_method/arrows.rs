@@ -0,0 +1,268 @@
+//! ### `Arrows` Display Method
+//!
+//! This module contains the `Arrows` display method for drawing vector fields stored as `(rows, columns, 2)` arrays.
+
+use core::f64::consts::FRAC_PI_4;
+use ndarray::{Array2, ArrayBase, Axis, Data, Ix3, s};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use crate::{
+    DisplayArray, Numeric,
+    display_method::{
+        DisplayMethod,
+        block::Block,
+        canvas::{Canvas, RESET, Scale},
+        number::Rounded,
+    },
+};
+
+/// Arrows pointing in each of eight directions, anticlockwise from the right.
+const LIGHT: [char; 8] = [
+    '\u{2192}', '\u{2197}', '\u{2191}', '\u{2196}', '\u{2190}', '\u{2199}', '\u{2193}', '\u{2198}',
+];
+
+/// Double arrows pointing in each of eight directions, anticlockwise from the right, drawn for strong vectors.
+const HEAVY: [char; 8] = [
+    '\u{21d2}', '\u{21d7}', '\u{21d1}', '\u{21d6}', '\u{21d0}', '\u{21d9}', '\u{21d3}', '\u{21d8}',
+];
+
+/// Marker drawn for vectors too short to have a meaningful direction.
+const STILL: char = '\u{b7}';
+
+/// Colours of increasing magnitudes, as ANSI escape sequences.
+const RAMP: [&str; 5] = ["\x1b[34m", "\x1b[36m", "\x1b[32m", "\x1b[33m", "\x1b[31m"];
+
+/// How the magnitude of each vector is shown.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Magnitude {
+    /// Arrows are coloured from blue to red by magnitude, using ANSI escape sequences.
+    Colour,
+    /// Arrows of at least half the largest magnitude are drawn as double arrows.
+    Weight,
+}
+
+/// Display method that draws each cell of a `(rows, columns, 2)` array of `(u, v)` vectors as an arrow.
+///
+/// The `u` component points right and the `v` component points up, and arrows are rounded to the nearest of eight
+/// directions. Side-by-side arrays share the same magnitude scale.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct Arrows {
+    /// Number of rows and columns averaged into each arrow.
+    pub step: usize,
+    /// How the magnitude of each vector is shown.
+    pub magnitude: Magnitude,
+    /// Fraction of the largest magnitude below which vectors are marked as near zero.
+    pub threshold: f64,
+    /// Number of decimal places shown in the magnitude labels.
+    pub precision: usize,
+}
+
+impl Arrows {
+    /// Construct a new `Arrows` instance which draws one arrow per cell, coloured by magnitude.
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            step: 1,
+            magnitude: Magnitude::Colour,
+            threshold: 0.05,
+            precision: 2,
+        }
+    }
+
+    /// Average blocks of the given number of rows and columns into each arrow.
+    #[must_use]
+    #[inline]
+    pub const fn with_step(mut self, step: usize) -> Self {
+        self.step = if step == 0 { 1 } else { step };
+        self
+    }
+
+    /// Set how the magnitude of each vector is shown.
+    #[must_use]
+    #[inline]
+    pub const fn with_magnitude(mut self, magnitude: Magnitude) -> Self {
+        self.magnitude = magnitude;
+        self
+    }
+
+    /// Set the fraction of the largest magnitude below which vectors are marked as near zero.
+    #[must_use]
+    #[inline]
+    pub const fn with_threshold(mut self, threshold: f64) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    /// Set the number of decimal places shown in the magnitude labels.
+    #[must_use]
+    #[inline]
+    pub const fn with_precision(mut self, precision: usize) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    /// Average the vectors of each block of an array, or describe why the array is not a vector field.
+    fn vectors<S>(&self, arr: &ArrayBase<S, Ix3>) -> Result<Array2<(f64, f64)>, String>
+    where
+        S: Data,
+        S::Elem: Numeric,
+    {
+        let (rows, cols, components) = arr.dim();
+        if components != 2 {
+            return Err(format!(
+                "Vector fields require a last axis of length 2, but the shape is {:?}",
+                arr.shape()
+            ));
+        }
+        let shape = (rows.div_ceil(self.step), cols.div_ceil(self.step));
+        Ok(Array2::from_shape_fn(shape, |(row, col)| {
+            let (top, left) = (row * self.step, col * self.step);
+            let block = arr.slice(s![top..(top + self.step).min(rows), left..(left + self.step).min(cols), ..]);
+            let count = block.len().div_euclid(2).to_f64();
+            let sums = block.lanes(Axis(2)).into_iter().fold((0.0, 0.0), |sums, vector| {
+                (sums.0 + vector[0].to_f64(), sums.1 + vector[1].to_f64())
+            });
+            (sums.0 / count, sums.1 / count)
+        }))
+    }
+
+    /// Arrow drawn for a vector, or the near-zero marker, given the largest magnitude of the fields.
+    #[expect(
+        clippy::as_conversions,
+        clippy::cast_possible_truncation,
+        reason = "The direction is rounded and wrapped into one of eight sectors."
+    )]
+    fn glyph(&self, vector: (f64, f64), largest: f64) -> char {
+        let length = vector.0.hypot(vector.1);
+        if !length.is_finite() {
+            ' '
+        } else if length <= self.threshold * largest {
+            STILL
+        } else {
+            let sector = ((vector.1.atan2(vector.0) / FRAC_PI_4).round() as i64).rem_euclid(8) as usize;
+            if self.magnitude == Magnitude::Weight && length >= largest / 2.0 {
+                HEAVY[sector]
+            } else {
+                LIGHT[sector]
+            }
+        }
+    }
+
+    /// Draw the arrows of a field, framed by the coordinates of the first cell of each block.
+    fn render(&self, vectors: &Array2<(f64, f64)>, scale: &Scale) -> Vec<String> {
+        let lines: Vec<String> = vectors
+            .rows()
+            .into_iter()
+            .map(|row| {
+                let mut line = String::new();
+                let mut current = None;
+                for (col, &vector) in row.iter().enumerate() {
+                    if col > 0 {
+                        line.push(' ');
+                    }
+                    let glyph = self.glyph(vector, scale.max);
+                    let colour = (self.magnitude == Magnitude::Colour && glyph != ' ')
+                        .then(|| RAMP[scale.bin(vector.0.hypot(vector.1), RAMP.len())]);
+                    if colour != current && glyph != ' ' {
+                        line.push_str(colour.unwrap_or(RESET));
+                        current = colour;
+                    }
+                    line.push(glyph);
+                }
+                if current.is_some() {
+                    line.push_str(RESET);
+                }
+                line
+            })
+            .collect();
+        let x = Scale {
+            min: 0.0,
+            max: (vectors.ncols().saturating_sub(1) * self.step).to_f64(),
+            precision: 0,
+        };
+        let y = Scale {
+            min: (vectors.nrows().saturating_sub(1) * self.step).to_f64(),
+            max: 0.0,
+            precision: 0,
+        };
+        Canvas::framed(&lines, (vectors.ncols() * 2).saturating_sub(1), &x, &y)
+    }
+
+    /// Describe how the magnitude of each vector is shown.
+    fn legend(&self, scale: &Scale) -> String {
+        let rounded = |value: f64| Rounded {
+            value,
+            precision: self.precision,
+        };
+        let mut entries: Vec<String> = match self.magnitude {
+            Magnitude::Colour => RAMP
+                .iter()
+                .enumerate()
+                .map(|(index, colour)| {
+                    let from = scale.label(index.to_f64() / RAMP.len().to_f64());
+                    format!("{colour}{}{RESET} >={from}", LIGHT[0])
+                })
+                .collect(),
+            Magnitude::Weight => vec![
+                format!("{} <{}", LIGHT[0], rounded(scale.max / 2.0)),
+                format!("{} >={}", HEAVY[0], rounded(scale.max / 2.0)),
+            ],
+        };
+        entries.push(format!("{STILL} <={}", rounded(self.threshold * scale.max)));
+        format!("magnitude: {}", entries.join("  "))
+    }
+}
+
+impl Default for Arrows {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DisplayMethod for Arrows {}
+
+impl<S> Display for DisplayArray<'_, S, Ix3, Arrows>
+where
+    S: Data,
+    S::Elem: Numeric,
+{
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        // The field is public, so a step of zero can bypass the builder
+        let settings = self.settings();
+        let method = &Arrows {
+            step: settings.step.max(1),
+            ..settings
+        };
+        let mut fields = Vec::new();
+        for arr in &self.arrays {
+            match method.vectors(arr) {
+                Ok(vectors) => fields.push(vectors),
+                Err(message) => return write!(f, "Error: {message}"),
+            }
+        }
+        let largest = fields
+            .iter()
+            .flatten()
+            .map(|vector| vector.0.hypot(vector.1))
+            .filter(|length| length.is_finite())
+            .fold(0.0, f64::max);
+        let scale = Scale {
+            min: 0.0,
+            max: if largest > 0.0 { largest } else { 1.0 },
+            precision: method.precision,
+        };
+
+        let panels: Vec<Block> = fields
+            .iter()
+            .map(|vectors| Block::new(method.render(vectors, &scale)))
+            .collect();
+        let mut lines = Block::beside(&panels, "  ").lines;
+        lines.push(method.legend(&scale));
+        write!(f, "{}", lines.join("\n"))
+    }
+}
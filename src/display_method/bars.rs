@@ -0,0 +1,144 @@
+//! ### Data bars
+//!
+//! This module contains functions which draw the bars of the `DataBars` option.
+
+use ndarray::{ArrayD, ArrayViewD};
+use std::iter::repeat_n;
+
+use crate::Numeric as _;
+
+/// Blocks filling one to eight eighths of a character from the left.
+pub const LEFT_EIGHTHS: [char; 8] = [
+    '\u{258f}', '\u{258e}', '\u{258d}', '\u{258c}', '\u{258b}', '\u{258a}', '\u{2589}', '\u{2588}',
+];
+
+/// Blocks filling one to eight eighths of a character from the bottom.
+pub const LOWER_EIGHTHS: [char; 8] = [
+    '\u{2581}', '\u{2582}', '\u{2583}', '\u{2584}', '\u{2585}', '\u{2586}', '\u{2587}', '\u{2588}',
+];
+
+/// Full block.
+const FULL: char = '\u{2588}';
+
+/// Line separating the bars of negative values from those of positive values.
+const ZERO_LINE: char = '\u{2502}';
+
+/// Block filling the given number of eighths of a character from the right.
+///
+/// Unicode only provides one-eighth, half and full right-aligned blocks, so other fractions are rounded to these.
+const fn right_eighths(eighths: usize) -> Option<char> {
+    match eighths {
+        0 => None,
+        1 | 2 => Some('\u{2595}'),
+        3..=5 => Some('\u{2590}'),
+        _ => Some(FULL),
+    }
+}
+
+/// Mapping of values onto bars of whole and partial characters.
+struct BarScale {
+    /// Smallest value drawn, which is zero unless any value is negative.
+    min: f64,
+    /// Largest value drawn, which is zero unless any value is positive.
+    max: f64,
+    /// Number of eighths of a character spanned by the full range of values.
+    span: f64,
+    /// Number of characters to the left of the zero line.
+    negative: usize,
+    /// Number of characters to the right of the zero line.
+    positive: usize,
+}
+
+impl BarScale {
+    /// Construct a scale fitting the given values, ignoring any which are not finite, into the given number of characters.
+    fn new(values: impl Iterator<Item = f64>, width: usize) -> Self {
+        let (min, max) = values
+            .filter(|value| value.is_finite())
+            .fold((0.0_f64, 0.0_f64), |(min, max), value| (min.min(value), max.max(value)));
+        let mut scale = Self {
+            min,
+            max,
+            span: (width * 8).to_f64(),
+            negative: 0,
+            positive: 0,
+        };
+        scale.negative = scale.eighths(min).div_ceil(8);
+        scale.positive = scale.eighths(max).div_ceil(8);
+        scale
+    }
+
+    /// Length of the bar of a value, in eighths of a character.
+    #[expect(
+        clippy::as_conversions,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        reason = "The length is rounded and bounded by the span of the scale."
+    )]
+    fn eighths(&self, value: f64) -> usize {
+        let range = self.max - self.min;
+        if range == 0.0 {
+            0
+        } else {
+            (value.clamp(self.min, self.max).abs() / range * self.span).round() as usize
+        }
+    }
+
+    /// Draw the bar of a value, padded to the width of the scale.
+    fn draw(&self, value: f64) -> String {
+        let eighths = if value.is_nan() { 0 } else { self.eighths(value) };
+        let mut bar = String::new();
+
+        if self.negative > 0 {
+            if value < 0.0 {
+                let (full, partial) = (eighths.div_euclid(8), eighths.rem_euclid(8));
+                let glyph = right_eighths(partial);
+                bar.extend(repeat_n(' ', self.negative - full - usize::from(glyph.is_some())));
+                bar.extend(glyph);
+                bar.extend(repeat_n(FULL, full));
+            } else {
+                bar.extend(repeat_n(' ', self.negative));
+            }
+            bar.push(ZERO_LINE);
+        }
+
+        if value > 0.0 {
+            let filled = horizontal_bar(eighths);
+            let length = filled.chars().count();
+            bar.push_str(&filled);
+            bar.extend(repeat_n(' ', self.positive - length));
+        } else {
+            bar.extend(repeat_n(' ', self.positive));
+        }
+        bar
+    }
+}
+
+/// Draw a bar of the given number of eighths of a character, growing from the left.
+#[inline]
+pub fn horizontal_bar(eighths: usize) -> String {
+    let mut bar: String = repeat_n(FULL, eighths.div_euclid(8)).collect();
+    if let Some(partial) = eighths.rem_euclid(8).checked_sub(1) {
+        bar.push(LEFT_EIGHTHS[partial]);
+    }
+    bar
+}
+
+/// Glyph of one line of a bar of the given number of eighths of a character, growing upwards from the given line.
+///
+/// Lines are counted upwards from zero at the base of the bar.
+#[inline]
+pub const fn vertical_bar(eighths: usize, line: usize) -> char {
+    let filled = eighths.saturating_sub(line * 8);
+    match filled {
+        0 => ' ',
+        1..=8 => LOWER_EIGHTHS[filled - 1],
+        _ => FULL,
+    }
+}
+
+/// Draw a bar for each element of a view, scaled to the range of all its values.
+#[inline]
+pub fn draw_bars<A>(view: &ArrayViewD<'_, A>, width: usize, numeric: fn(&A) -> f64) -> ArrayD<String> {
+    let scale = BarScale::new(view.iter().map(numeric), width);
+    view.map(|elem| scale.draw(numeric(elem)))
+}
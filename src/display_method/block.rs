@@ -0,0 +1,72 @@
+//! ### `Block`
+//!
+//! This module contains the `Block` type, a rectangular piece of text used to lay out rendered arrays.
+
+/// Lines of text that are composed horizontally and vertically to build the final output.
+#[derive(Debug, Clone, Default)]
+pub struct Block {
+    /// The lines of the block, without trailing newlines.
+    pub lines: Vec<String>,
+}
+
+impl Block {
+    /// Construct a new `Block` from the given lines.
+    #[must_use]
+    pub const fn new(lines: Vec<String>) -> Self {
+        Self { lines }
+    }
+
+    /// Number of characters in the widest line.
+    #[must_use]
+    pub fn width(&self) -> usize {
+        self.lines.iter().map(|line| visible_width(line)).max().unwrap_or(0)
+    }
+
+    /// Place the given blocks side by side, padding every block but the last to its own width.
+    #[must_use]
+    pub fn beside(blocks: &[Self], separator: &str) -> Self {
+        let height = blocks.iter().map(|block| block.lines.len()).max().unwrap_or(0);
+        let widths: Vec<usize> = blocks.iter().map(Self::width).collect();
+
+        let lines = (0..height)
+            .map(|row| {
+                let mut line = String::new();
+                for (index, block) in blocks.iter().enumerate() {
+                    let text = block.lines.get(row).map_or("", String::as_str);
+                    line.push_str(text);
+                    if index + 1 < blocks.len() {
+                        line.push_str(&" ".repeat(widths[index] - visible_width(text)));
+                        line.push_str(separator);
+                    }
+                }
+                line
+            })
+            .collect();
+
+        Self { lines }
+    }
+
+    /// Prefix every line with the corresponding line of `gutter`, padding the gutter to a common width.
+    #[must_use]
+    pub fn with_gutter(self, gutter: &Self) -> Self {
+        Self::beside(&[gutter.clone(), self], "")
+    }
+}
+
+/// Number of characters in a line which are shown, skipping ANSI colour escape sequences.
+#[must_use]
+pub fn visible_width(line: &str) -> usize {
+    let mut escaped = false;
+    line.chars()
+        .filter(|&character| {
+            if character == '\x1b' {
+                escaped = true;
+            } else if escaped {
+                escaped = character != 'm';
+            } else {
+                return true;
+            }
+            false
+        })
+        .count()
+}
@@ -0,0 +1,222 @@
+//! ### `BoxPlot` Display Method
+//!
+//! This module contains the `BoxPlot` display method for comparing the distributions of the columns or rows of arrays.
+
+use ndarray::{ArrayView1, Axis, Data, Ix2};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use crate::{
+    DisplayArray, Numeric,
+    display_method::{
+        DisplayMethod,
+        canvas::{Canvas, Scale},
+    },
+};
+
+/// Multiple of the interquartile range beyond the quartiles at which values are outliers.
+const WHISKER_REACH: f64 = 1.5;
+
+/// Whether distributions are taken down the columns or across the rows of an array.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Groups {
+    /// One distribution per column, such as per feature of a `(samples, features)` array.
+    Columns,
+    /// One distribution per row.
+    Rows,
+}
+
+/// Display method that draws a box-and-whisker summary of each column (or row), one per line on a shared axis.
+///
+/// Boxes span the quartiles around the median, whiskers reach the furthest values within one and a half interquartile
+/// ranges of the box, and values beyond the whiskers are marked as outliers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct BoxPlot {
+    /// Number of characters across the shared axis.
+    pub width: usize,
+    /// Whether distributions are taken down the columns or across the rows.
+    pub groups: Groups,
+    /// Name of each column or row. Groups are labelled by index if empty.
+    pub labels: Vec<String>,
+    /// Number of decimal places shown in the axis labels.
+    pub precision: usize,
+}
+
+impl BoxPlot {
+    /// Construct a new `BoxPlot` instance which summarises each column on an axis of the given number of characters.
+    #[must_use]
+    #[inline]
+    pub const fn new(width: usize) -> Self {
+        Self {
+            width: if width == 0 { 1 } else { width },
+            groups: Groups::Columns,
+            labels: Vec::new(),
+            precision: 2,
+        }
+    }
+
+    /// Set whether distributions are taken down the columns or across the rows.
+    #[must_use]
+    #[inline]
+    pub const fn with_groups(mut self, groups: Groups) -> Self {
+        self.groups = groups;
+        self
+    }
+
+    /// Set the name of each column or row.
+    #[must_use]
+    #[inline]
+    pub fn with_labels<I, L>(mut self, labels: I) -> Self
+    where
+        I: IntoIterator<Item = L>,
+        L: Into<String>,
+    {
+        self.labels = labels.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Set the number of decimal places shown in the axis labels.
+    #[must_use]
+    #[inline]
+    pub const fn with_precision(mut self, precision: usize) -> Self {
+        self.precision = precision;
+        self
+    }
+}
+
+impl Default for BoxPlot {
+    #[inline]
+    fn default() -> Self {
+        Self::new(60)
+    }
+}
+
+impl DisplayMethod for BoxPlot {}
+
+/// Five-number summary of a distribution, with its outliers.
+struct Summary {
+    /// Smallest value within the lower whisker.
+    low: f64,
+    /// Lower quartile.
+    q1: f64,
+    /// Median.
+    median: f64,
+    /// Upper quartile.
+    q3: f64,
+    /// Largest value within the upper whisker.
+    high: f64,
+    /// Values beyond the whiskers.
+    outliers: Vec<f64>,
+}
+
+impl Summary {
+    /// Summarise the finite values of a lane, if there are any.
+    fn new<A>(lane: ArrayView1<'_, A>, numeric: fn(&A) -> f64) -> Option<Self> {
+        let mut values: Vec<f64> = lane.iter().map(numeric).filter(|value| value.is_finite()).collect();
+        values.sort_by(f64::total_cmp);
+        let (q1, median, q3) = (quantile(&values, 0.25)?, quantile(&values, 0.5)?, quantile(&values, 0.75)?);
+        let reach = WHISKER_REACH * (q3 - q1);
+        let (fence_low, fence_high) = (q1 - reach, q3 + reach);
+        let inside = || {
+            values
+                .iter()
+                .copied()
+                .filter(|value| (fence_low..=fence_high).contains(value))
+        };
+        Some(Self {
+            low: inside().next().unwrap_or(q1),
+            q1,
+            median,
+            q3,
+            high: inside().next_back().unwrap_or(q3),
+            outliers: values
+                .iter()
+                .copied()
+                .filter(|value| !(fence_low..=fence_high).contains(value))
+                .collect(),
+        })
+    }
+
+    /// Draw the summary across the given number of characters of the scale.
+    fn draw(&self, scale: &Scale, width: usize) -> String {
+        let at = |value: f64| scale.step(value, width);
+        let mut line = vec![' '; width];
+        line[at(self.low)..=at(self.high)].fill('\u{2500}');
+        line[at(self.q1)..=at(self.q3)].fill('\u{2592}');
+        line[at(self.low)] = '\u{251c}';
+        line[at(self.high)] = '\u{2524}';
+        line[at(self.q1)] = '[';
+        line[at(self.q3)] = ']';
+        line[at(self.median)] = '\u{2503}';
+        for &outlier in &self.outliers {
+            line[at(outlier)] = '\u{2022}';
+        }
+        line.into_iter().collect()
+    }
+}
+
+/// Value at the given fraction through sorted values, interpolating linearly between neighbours.
+#[expect(
+    clippy::as_conversions,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss,
+    reason = "The position is non-negative and within the values."
+)]
+fn quantile(sorted: &[f64], fraction: f64) -> Option<f64> {
+    let last = sorted.len().checked_sub(1)?;
+    let position = fraction * last.to_f64();
+    let below = position.floor() as usize;
+    let above = (below + 1).min(last);
+    Some((sorted[above] - sorted[below]).mul_add(position - position.floor(), sorted[below]))
+}
+
+impl<S> Display for DisplayArray<'_, S, Ix2, BoxPlot>
+where
+    S: Data,
+    S::Elem: Numeric,
+{
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let method = &self.settings();
+        // The field is public, so a width of zero can bypass the builder
+        let width = method.width.max(1);
+        let axis = match method.groups {
+            Groups::Columns => Axis(1),
+            Groups::Rows => Axis(0),
+        };
+
+        // Summarise every group of every array, labelling each by its name or index
+        let mut groups = Vec::new();
+        for (array, arr) in self.arrays.iter().enumerate() {
+            for (index, lane) in arr.axis_iter(axis).enumerate() {
+                let name = method.labels.get(index).cloned().unwrap_or_else(|| index.to_string());
+                let label = if self.arrays.len() > 1 {
+                    format!("{array}:{name}")
+                } else {
+                    name
+                };
+                groups.push((label, Summary::new(lane, Numeric::to_f64)));
+            }
+        }
+
+        let scale = Scale::fit(
+            groups.iter().filter_map(|group| group.1.as_ref()).flat_map(|summary| {
+                [summary.low, summary.high]
+                    .into_iter()
+                    .chain(summary.outliers.iter().copied())
+            }),
+            method.precision,
+        );
+        let label_width = groups.iter().map(|group| group.0.chars().count()).max().unwrap_or(0);
+        let mut lines: Vec<String> = groups
+            .iter()
+            .map(|group| {
+                let plot = group.1.as_ref().map_or_else(String::new, |found| found.draw(&scale, width));
+                format!("{:>label_width$} \u{2502}{plot}", group.0)
+            })
+            .collect();
+        lines.extend(Canvas::x_axis(width, &scale, label_width));
+        write!(f, "{}", lines.join("\n"))
+    }
+}
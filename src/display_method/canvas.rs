@@ -0,0 +1,313 @@
+//! ### `Canvas`
+//!
+//! This module contains the `Canvas` type, a grid of Braille dots used to draw plots, and the `Scale` of its axes.
+
+use crate::{Numeric as _, display_method::number::Rounded};
+
+/// Colours of successive series, as ANSI escape sequences.
+const PALETTE: [&str; 6] = ["\x1b[34m", "\x1b[31m", "\x1b[32m", "\x1b[33m", "\x1b[35m", "\x1b[36m"];
+
+/// ANSI escape sequence which restores the default colour.
+pub const RESET: &str = "\x1b[0m";
+
+/// Code point of the blank Braille pattern, to which the dot bits are added.
+const BRAILLE: u32 = 0x2800;
+
+/// Bit of each dot in a Braille cell, indexed by row and then column.
+const DOT_BITS: [[u32; 2]; 4] = [[0x01, 0x08], [0x02, 0x10], [0x04, 0x20], [0x40, 0x80]];
+
+/// Minimum number of characters between the starts of neighbouring tick labels on the horizontal axis.
+const X_TICK_SPACING: usize = 10;
+
+/// Minimum number of lines between neighbouring tick labels on the vertical axis.
+const Y_TICK_SPACING: usize = 4;
+
+/// Colour used to mark the series at the given index.
+#[inline]
+pub const fn colour(series: usize) -> &'static str {
+    PALETTE[series.rem_euclid(PALETTE.len())]
+}
+
+/// Wrap text in the colour of the given series.
+#[inline]
+pub fn paint(text: &str, series: usize) -> String {
+    format!("{}{text}{RESET}", colour(series))
+}
+
+/// Range of values spanned by one axis of a canvas.
+#[derive(Debug, Clone, Copy)]
+pub struct Scale {
+    /// Value at the start of the axis.
+    pub min: f64,
+    /// Value at the end of the axis.
+    pub max: f64,
+    /// Number of decimal places shown in tick labels.
+    pub precision: usize,
+}
+
+impl Scale {
+    /// Construct a scale spanning the finite values given, widened if they are all equal.
+    #[inline]
+    pub fn fit<I: Iterator<Item = f64>>(values: I, precision: usize) -> Self {
+        let (min, max) = values
+            .filter(|value| value.is_finite())
+            .fold(None, |range: Option<(f64, f64)>, value| {
+                Some(range.map_or((value, value), |(min, max)| (min.min(value), max.max(value))))
+            })
+            .unwrap_or((0.0, 1.0));
+        let (low, high) = if min < max { (min, max) } else { (min - 1.0, max + 1.0) };
+        Self {
+            min: low,
+            max: high,
+            precision,
+        }
+    }
+
+    /// Position of a value along the axis, from zero at the start to one at the end.
+    #[inline]
+    pub fn fraction(&self, value: f64) -> f64 {
+        (value - self.min) / (self.max - self.min)
+    }
+
+    /// Index of the step of a value along an axis divided into the given number of steps, clamped to the axis.
+    #[expect(
+        clippy::as_conversions,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        reason = "The step is rounded and clamped to the axis."
+    )]
+    #[inline]
+    pub fn step(&self, value: f64, steps: usize) -> usize {
+        let last = steps.saturating_sub(1).to_f64();
+        (self.fraction(value) * last).round().clamp(0.0, last) as usize
+    }
+
+    /// Index of the equal-width bin holding a value, for the axis divided into the given number of bins.
+    #[expect(
+        clippy::as_conversions,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        reason = "The bin is rounded down and clamped to the axis."
+    )]
+    #[inline]
+    pub fn bin(&self, value: f64, bins: usize) -> usize {
+        let count = bins.to_f64();
+        (self.fraction(value) * count).floor().clamp(0.0, count - 1.0) as usize
+    }
+
+    /// Label of the value at the given fraction along the axis.
+    #[inline]
+    pub fn label(&self, fraction: f64) -> String {
+        Rounded {
+            value: fraction.mul_add(self.max - self.min, self.min),
+            precision: self.precision,
+        }
+        .to_string()
+    }
+}
+
+/// Grid of characters, each holding two columns and four rows of Braille dots.
+///
+/// Each character is drawn in the colour of the last series to place a dot in it, if colours are enabled.
+#[derive(Debug, Clone)]
+pub struct Canvas {
+    /// Number of characters across.
+    pub width: usize,
+    /// Number of characters down.
+    pub height: usize,
+    /// Dot bits of each character, row by row.
+    pub cells: Vec<u32>,
+    /// Series which last drew in each character, row by row.
+    pub series: Vec<Option<usize>>,
+    /// Characters drawn in place of the dots of each character, row by row.
+    pub glyphs: Vec<Option<char>>,
+    /// Whether characters are coloured by series.
+    pub colours: bool,
+}
+
+impl Canvas {
+    /// Construct a blank canvas of the given number of characters across and down.
+    #[inline]
+    pub fn new(width: usize, height: usize, colours: bool) -> Self {
+        let (w, h) = (width.max(1), height.max(1));
+        Self {
+            width: w,
+            height: h,
+            cells: vec![0; w * h],
+            series: vec![None; w * h],
+            glyphs: vec![None; w * h],
+            colours,
+        }
+    }
+
+    /// Number of dots across.
+    #[inline]
+    pub const fn dot_width(&self) -> usize {
+        self.width * 2
+    }
+
+    /// Number of dots down.
+    #[inline]
+    pub const fn dot_height(&self) -> usize {
+        self.height * 4
+    }
+
+    /// Place a dot, counting from the top left, ignoring dots outside the canvas.
+    #[inline]
+    pub fn set(&mut self, x: usize, y: usize, series: usize) {
+        if x < self.dot_width() && y < self.dot_height() {
+            let cell = y.div_euclid(4) * self.width + x.div_euclid(2);
+            self.cells[cell] |= DOT_BITS[y.rem_euclid(4)][x.rem_euclid(2)];
+            self.series[cell] = Some(series);
+        }
+    }
+
+    /// Draw a character in place of the dots of the character at the given column and row.
+    #[inline]
+    pub fn put(&mut self, col: usize, row: usize, glyph: char, series: usize) {
+        if col < self.width && row < self.height {
+            let cell = row * self.width + col;
+            self.glyphs[cell] = Some(glyph);
+            self.series[cell] = Some(series);
+        }
+    }
+
+    /// Draw a straight line of dots between two points, inclusive.
+    #[expect(
+        clippy::as_conversions,
+        clippy::cast_possible_wrap,
+        clippy::cast_sign_loss,
+        reason = "Dot coordinates are far smaller than the range of either type, and only non-negative points are drawn."
+    )]
+    #[inline]
+    pub fn line(&mut self, from: (usize, usize), to: (usize, usize), series: usize) {
+        let (mut x, mut y) = (from.0 as isize, from.1 as isize);
+        let (x1, y1) = (to.0 as isize, to.1 as isize);
+        let (dx, dy) = ((x1 - x).abs(), -(y1 - y).abs());
+        let (sx, sy) = (if x < x1 { 1 } else { -1 }, if y < y1 { 1 } else { -1 });
+        let mut error = dx + dy;
+        loop {
+            self.set(x as usize, y as usize, series);
+            if x == x1 && y == y1 {
+                break;
+            }
+            let doubled = 2 * error;
+            if doubled >= dy {
+                error += dy;
+                x += sx;
+            }
+            if doubled <= dx {
+                error += dx;
+                y += sy;
+            }
+        }
+    }
+
+    /// Render each row of characters, with empty characters left blank.
+    ///
+    /// Colour escape sequences are only written where the colour changes.
+    #[inline]
+    pub fn rows(&self) -> Vec<String> {
+        (0..self.height)
+            .map(|row| {
+                let mut line = String::new();
+                let mut current = None;
+                for col in 0..self.width {
+                    let cell = row * self.width + col;
+                    let glyph = self.glyphs[cell].unwrap_or_else(|| match self.cells[cell] {
+                        0 => ' ',
+                        bits => char::from_u32(BRAILLE + bits).unwrap_or(' '),
+                    });
+                    let series = self.series[cell].filter(|_| self.colours && glyph != ' ');
+                    if series != current && glyph != ' ' {
+                        line.push_str(series.map_or(RESET, colour));
+                        current = series;
+                    }
+                    line.push(glyph);
+                }
+                if current.is_some() {
+                    line.push_str(RESET);
+                }
+                line
+            })
+            .collect()
+    }
+
+    /// Rows of a canvas of the given height which carry tick labels on the vertical axis, from the top.
+    ///
+    /// The first and last rows are always labelled, and inner ticks are kept clear of the last.
+    #[inline]
+    pub fn tick_rows(height: usize) -> Vec<usize> {
+        (0..height)
+            .filter(|&row| row == 0 || (row.is_multiple_of(Y_TICK_SPACING) && row + Y_TICK_SPACING <= height))
+            .chain((height > 1).then(|| height - 1))
+            .collect()
+    }
+
+    /// Render the given rows inside a frame with tick labels along the left and bottom edges.
+    #[inline]
+    pub fn framed(rows: &[String], width: usize, x: &Scale, y: &Scale) -> Vec<String> {
+        let height = rows.len();
+        let last_row = height.saturating_sub(1).max(1).to_f64();
+        let tick_rows = Self::tick_rows(height);
+        let y_labels: Vec<Option<String>> = (0..height)
+            .map(|row| tick_rows.contains(&row).then(|| y.label(1.0 - row.to_f64() / last_row)))
+            .collect();
+        Self::labelled(rows, width, x, &y_labels)
+    }
+
+    /// Render the given rows inside a frame with the given tick label, if any, beside each row and tick labels along the
+    /// bottom edge.
+    #[inline]
+    pub fn labelled(rows: &[String], width: usize, x: &Scale, y_labels: &[Option<String>]) -> Vec<String> {
+        let label_width = y_labels.iter().flatten().map(String::len).max().unwrap_or(0);
+
+        let mut lines: Vec<String> = rows
+            .iter()
+            .zip(y_labels)
+            .map(|(row, label)| {
+                label.as_ref().map_or_else(
+                    || format!("{} \u{2502}{row}", " ".repeat(label_width)),
+                    |text| format!("{text:>label_width$} \u{2524}{row}"),
+                )
+            })
+            .collect();
+
+        lines.extend(Self::x_axis(width, x, label_width));
+        lines
+    }
+
+    /// Lines of a horizontal axis spanning the given number of characters, with its tick labels beneath.
+    ///
+    /// The axis is indented to follow a column of labels of the given width. Each tick stands at the column of its rounded
+    /// label, so that an axis of few whole values ticks each value once, and labels which would collide are skipped.
+    #[inline]
+    pub fn x_axis(width: usize, x: &Scale, label_width: usize) -> [String; 2] {
+        let last_col = width.saturating_sub(1).max(1).to_f64();
+        let mut ticks: Vec<(usize, String)> = Vec::new();
+        for col in (0..width).filter(|col| col.is_multiple_of(X_TICK_SPACING) || col + 1 == width) {
+            let label = x.label(col.to_f64() / last_col);
+            let at = label.parse().map_or(col, |value| x.step(value, width));
+            if ticks.last().is_none_or(|last| last.0 < at && last.1 != label) {
+                ticks.push((at, label));
+            }
+        }
+
+        let mut axis = format!("{} \u{2514}", " ".repeat(label_width));
+        let mut labels = " ".repeat(label_width + 2);
+        let mut pending = ticks.into_iter().peekable();
+        for col in 0..width {
+            let cursor = labels.chars().count();
+            let start = label_width + 2 + col;
+            match pending.next_if(|tick| tick.0 == col) {
+                Some((_, label)) if start >= cursor + usize::from(col > 0) => {
+                    labels.push_str(&" ".repeat(start - cursor));
+                    labels.push_str(&label);
+                    axis.push('\u{252c}');
+                }
+                _ => axis.push('\u{2500}'),
+            }
+        }
+        [axis, labels]
+    }
+}
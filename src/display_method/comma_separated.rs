@@ -9,7 +9,7 @@ use super::{DisplayMethod, common, formatter::ElementFormatter};
 use crate::DisplayArray;
 
 /// Display method that prints each element with a comma and space as a separator.
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, Default)]
 #[non_exhaustive]
 pub struct CommaSeparated;
 
@@ -2,179 +2,1131 @@
 //!
 //! This module contains shared formatting logic used by all display methods.
 
-use ndarray::{ArrayBase, Data, Dimension};
-use std::fmt::{Display, Formatter, Result as FmtResult};
+use ndarray::{ArrayD, ArrayView2, ArrayViewD, Axis, Data, Dimension, Ix2, IxDyn, Slice, Zip, indices, s};
+use std::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    iter::{once, repeat_n},
+    mem,
+    ops::Range,
+};
 
 use crate::{
-    DisplayArray,
-    display_method::{DisplayMethod, formatter::ElementFormatter},
+    BarPlacement, DataBars, DisplayArray, Marginals, Mosaic, OrthoSlices, Overview, Rulers, SliceHeaders, Wrap,
+    display_method::{
+        DisplayMethod, bars::draw_bars, block::Block, describe::describe, formatter::ElementFormatter, number::Rounded,
+        stats::summarise,
+    },
 };
 
-/// Format a single array using the given element formatter.
-#[inline]
-pub fn fmt_single_array<S, D, F>(arr: &ArrayBase<S, D>, f: &mut Formatter<'_>, element_formatter: &F) -> FmtResult
-where
-    S: Data,
-    S::Elem: Display,
-    D: Dimension,
-    F: ElementFormatter,
-{
-    let shape = arr.shape();
-    let ndim = shape.len();
+/// Marker placed beside rows which continue past the left or right edge of a window.
+const ELLIPSIS: &str = "\u{2026}";
+
+/// Marker placed to the left of the row crossed by another plane.
+const ROW_MARKER: char = '\u{25b8}';
+
+/// Marker placed above the column crossed by another plane.
+const COLUMN_MARKER: char = '\u{25be}';
+
+/// Marker placed above or below columns which continue past the top or bottom edge of a window.
+const VERTICAL_ELLIPSIS: &str = "\u{22ee}";
+
+/// Adaptor which renders a single element through an element formatter.
+struct Cell<'a, F, T> {
+    /// Formatter used to render the element.
+    formatter: &'a F,
+    /// The element to render.
+    elem: &'a T,
+    /// Width to pad the element to.
+    width: usize,
+    /// Whether the element is the last in its row.
+    is_last_in_row: bool,
+}
+
+impl<F: ElementFormatter, T: Display> Display for Cell<'_, F, T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        self.formatter.format_element(f, self.elem, self.width, self.is_last_in_row)
+    }
+}
+
+/// Adaptor which renders the spacing placed between side-by-side arrays.
+struct ArraySeparator<'a, F>(&'a F);
+
+impl<F: ElementFormatter> Display for ArraySeparator<'_, F> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        self.0.write_array_separator(f)
+    }
+}
+
+/// Rendered marginal reductions of an array, for each of its pages.
+struct Margins {
+    /// Reduction of each row, indexed by page position and then row.
+    rows: ArrayD<String>,
+    /// Reduction of each column, indexed by page position and then column.
+    columns: ArrayD<String>,
+    /// Reduction of each whole page, indexed by page position.
+    corners: ArrayD<String>,
+    /// Width of the widest row or page reduction.
+    width: usize,
+}
+
+/// Settings shared by every page of a single display.
+struct Context<'a, F> {
+    /// Formatter used to render each element.
+    formatter: &'a F,
+    /// Rendered spacing placed between side-by-side arrays.
+    separator: String,
+    /// Padding width of the elements of each array.
+    widths: Vec<usize>,
+    /// Number of dimensions of the displayed arrays.
+    ndim: usize,
+    /// Original axis of each traversed axis, with the page axes first.
+    order: Vec<usize>,
+    /// Group of each page axis, in traversal order. Pages are separated by a blank line for each group which changes.
+    page_groups: Vec<usize>,
+    /// Original index of the first displayed element along each original axis.
+    origin: Vec<usize>,
+    /// Whether elements are hidden before and after the displayed region along each original axis.
+    clipped: Vec<(bool, bool)>,
+    /// Number of pages hidden before the first displayed page, and the number hidden elsewhere, in traversal order.
+    hidden_pages: (usize, usize),
+    /// Number of original elements pooled into each displayed element along each original axis.
+    scale: Vec<usize>,
+    /// Optional index labels.
+    rulers: Option<Rulers>,
+    /// Optional position headers for each page.
+    slice_headers: Option<SliceHeaders>,
+    /// Optional line width at which pages are split into blocks of columns.
+    wrap: Option<Wrap>,
+    /// Lines placed above each array, before its first page.
+    headers: Vec<Vec<String>>,
+    /// Lines placed below each array, after its last page.
+    footers: Vec<Vec<String>>,
+    /// Optional per-row and per-column reductions of each 2-D block.
+    marginals: Option<Marginals>,
+    /// Marginal reductions of each array, if shown.
+    margins: Vec<Margins>,
+    /// Optional bars drawn in proportion to each element.
+    data_bars: Option<DataBars>,
+    /// Bars drawn beneath the elements of each array, if shown.
+    bars: Vec<ArrayD<String>>,
+    /// Original coordinates of the point whose row and column are marked on each page, if any.
+    crosshair: Option<Vec<usize>>,
+}
+
+impl<F> Context<'_, F> {
+    /// Number of lines placed above the first page of each array.
+    fn header_height(&self) -> usize {
+        self.headers.iter().map(Vec::len).max().unwrap_or(0)
+    }
+
+    /// Minimum width of each array's panel, so that its decorations fit.
+    fn min_width(&self, index: usize) -> usize {
+        self.headers[index]
+            .iter()
+            .chain(&self.footers[index])
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Original axis displayed down the rows of each page, if any.
+    fn row_axis(&self) -> Option<usize> {
+        (self.ndim >= 2).then(|| self.order[self.ndim - 2])
+    }
 
-    // 1) compute widths for padding
-    let width = arr.iter().map(|e| e.to_string().len()).max().unwrap_or(0);
+    /// Original axis displayed across the columns of each page, if any.
+    fn column_axis(&self) -> Option<usize> {
+        (self.ndim >= 1).then(|| self.order[self.ndim - 1])
+    }
 
-    // 2) chunk sizes: one per axis (except last) = prod(shape[k+1..])
-    let chunk_sizes: Vec<usize> = (0..ndim.saturating_sub(1)).map(|k| shape[k + 1..].iter().product()).collect();
+    /// Whether elements are hidden before and after the displayed region along the given axis.
+    fn clipping(&self, axis: Option<usize>) -> (bool, bool) {
+        axis.map_or((false, false), |a| self.clipped[a])
+    }
 
-    // for 1D use full length, else first chunk
-    let row_size = if ndim == 1 { shape[0] } else { chunk_sizes[0] };
+    /// Original index of the displayed element at the given index along the given axis.
+    fn coordinate(&self, axis: Option<usize>, index: usize) -> usize {
+        axis.map_or(index, |a| self.origin[a] + index * self.scale[a])
+    }
 
-    // total elements to suppress final newline
-    let total = arr.len();
+    /// Index along the given axis of the displayed element holding the crosshair, if any.
+    fn marked(&self, axis: Option<usize>) -> Option<usize> {
+        let (a, point) = (axis?, self.crosshair.as_ref()?);
+        point[a]
+            .checked_sub(self.origin[a])
+            .map(|offset| offset.div_euclid(self.scale[a]))
+    }
 
-    for (i, elem) in arr.iter().enumerate() {
-        let is_last_in_row = (i + 1) % row_size == 0;
-        element_formatter.format_element(f, elem, width, is_last_in_row)?;
+    /// Append the row reductions of an array's page to its rows, and return the lines of column reductions placed below.
+    ///
+    /// Row reductions are only placed beside the range of columns which ends the page.
+    fn add_margins(
+        &self,
+        index: usize,
+        position: &[usize],
+        columns: &Range<usize>,
+        is_last_range: bool,
+        blank_indent: &str,
+        body: &mut [String],
+    ) -> Vec<String>
+    where
+        F: ElementFormatter,
+    {
+        let Some(margins) = self.margins.get(index) else {
+            return Vec::new();
+        };
+        let column_totals: String = at_position(&margins.columns, position)
+            .slice_move(s![columns.clone()])
+            .iter()
+            .enumerate()
+            .map(|(col, elem)| {
+                Cell {
+                    formatter: self.formatter,
+                    elem,
+                    width: self.widths[index],
+                    is_last_in_row: col + 1 == columns.len(),
+                }
+                .to_string()
+            })
+            .collect();
+        let mut totals = format!("{blank_indent}{column_totals}");
+        let body_width = body
+            .iter()
+            .chain(once(&totals))
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or(0);
+        let mut rule = "-".repeat(body_width);
 
-        // newline at end of any axis-block, but not after the very last element
-        for &chunk in &chunk_sizes {
-            if (i + 1) % chunk == 0 && (i + 1) < total {
-                writeln!(f)?;
+        if is_last_range {
+            let width = margins.width;
+            let row_totals = at_position(&margins.rows, position);
+            let corner = at_position(&margins.corners, position);
+            for (line, total) in body
+                .iter_mut()
+                .zip(&row_totals)
+                .chain(once((&mut totals, corner.first().unwrap())))
+            {
+                line.push_str(&" ".repeat(body_width - line.chars().count()));
+                line.push_str(" | ");
+                line.push_str(&" ".repeat(width - total.chars().count()));
+                line.push_str(total);
             }
+            rule.push_str("-+-");
+            rule.push_str(&"-".repeat(width));
         }
+        vec![rule, totals]
     }
+}
 
-    Ok(())
+/// View of the entries of an array at the given position along its leading axes.
+#[inline]
+fn at_position<'v>(array: &'v ArrayD<String>, position: &[usize]) -> ArrayViewD<'v, String> {
+    position
+        .iter()
+        .fold(array.view(), |view, &index| view.index_axis_move(Axis(0), index))
 }
 
-/// Format multiple arrays using the given element formatter.
+/// Render the marginal reductions of each page of a view.
 #[inline]
-pub fn fmt_multiple_arrays<S, D, F>(arrays: &[&ArrayBase<S, D>], f: &mut Formatter<'_>, element_formatter: &F) -> FmtResult
-where
-    S: Data,
-    S::Elem: Display,
-    D: Dimension,
-    F: ElementFormatter,
-{
-    if arrays.is_empty() {
-        return Ok(());
+fn margins<A>(view: &ArrayViewD<'_, A>, marginals: &Marginals, numeric: fn(&A) -> f64) -> Margins {
+    let reduce = |values: Vec<f64>| marginals.reduction.reduce(&values);
+    let ndim = view.ndim();
+    let row_values = view.map_axis(Axis(ndim - 1), |lane| reduce(lane.iter().map(numeric).collect()));
+    let column_values = view.map_axis(Axis(ndim - 2), |lane| reduce(lane.iter().map(numeric).collect()));
+    let corner_values = ArrayD::from_shape_fn(IxDyn(&view.shape()[..ndim - 2]), |index| {
+        reduce(page_view(view, index.slice()).iter().map(numeric).collect())
+    });
+
+    // The row and page reductions share a column, and so a number of decimal places, apart from the column reductions
+    let render = |precision: usize| move |&value: &f64| Rounded { value, precision }.to_string();
+    let margin_precision = Rounded::shared_precision(row_values.iter().chain(&corner_values), marginals.precision);
+    let rows = row_values.map(render(margin_precision));
+    let corners = corner_values.map(render(margin_precision));
+    let columns = column_values.map(render(Rounded::shared_precision(&column_values, marginals.precision)));
+    let width = rows
+        .iter()
+        .chain(&corners)
+        .map(|total| total.chars().count())
+        .max()
+        .unwrap_or(0);
+    Margins {
+        rows,
+        columns,
+        corners,
+        width,
     }
+}
 
-    // Make sure all arrays have the same shape
-    let first_shape = arrays[0].shape();
-    for (i, arr) in arrays.iter().enumerate().skip(1) {
-        if arr.shape() != first_shape {
-            return write!(
-                f,
-                "Error: Arrays have different shapes. Array 0 shape: {:?}, Array {} shape: {:?}",
-                first_shape,
-                i,
-                arr.shape()
-            );
+/// Width of the widest element of an array.
+#[inline]
+fn element_width<A: Display>(view: &ArrayViewD<'_, A>) -> usize {
+    view.iter().map(|e| e.to_string().chars().count()).max().unwrap_or(0)
+}
+
+/// Extract the 2-D page found at the given position along the leading axes.
+///
+/// Arrays with fewer than two dimensions are promoted to a single row.
+#[inline]
+fn page_view<'v, A>(view: &ArrayViewD<'v, A>, position: &[usize]) -> ArrayView2<'v, A> {
+    let mut page = position
+        .iter()
+        .fold(view.clone(), |page, &index| page.index_axis_move(Axis(0), index));
+    while page.ndim() < 2 {
+        page = page.insert_axis(Axis(0));
+    }
+    page.into_dimensionality::<Ix2>().unwrap()
+}
+
+/// Render every element of a page, row by row.
+#[inline]
+fn render_cells<A: Display, F: ElementFormatter>(page: &ArrayView2<'_, A>, width: usize, formatter: &F) -> Vec<Vec<String>> {
+    let ncols = page.ncols();
+    page.rows()
+        .into_iter()
+        .map(|row| {
+            row.iter()
+                .enumerate()
+                .map(|(col, elem)| {
+                    Cell {
+                        formatter,
+                        elem,
+                        width,
+                        is_last_in_row: col + 1 == ncols,
+                    }
+                    .to_string()
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Character offset at which the content of each column ends, excluding any separator.
+#[inline]
+fn column_ends<A: Display, F: ElementFormatter>(
+    page: &ArrayView2<'_, A>,
+    cells: &[Vec<String>],
+    width: usize,
+    formatter: &F,
+) -> Vec<usize> {
+    let mut offset = 0;
+    page.columns()
+        .into_iter()
+        .enumerate()
+        .map(|(col, column)| {
+            let content = column
+                .iter()
+                .map(|elem| {
+                    Cell {
+                        formatter,
+                        elem,
+                        width,
+                        is_last_in_row: true,
+                    }
+                    .to_string()
+                    .chars()
+                    .count()
+                })
+                .max()
+                .unwrap_or(0);
+            let end = offset + content;
+            offset += cells.iter().map(|row| row[col].chars().count()).max().unwrap_or(0);
+            end
+        })
+        .collect()
+}
+
+/// Smallest interval between column labels for which no two labels collide, as the step of the rulers times 1, 2, 5,
+/// 10, 20, 50 and so on.
+///
+/// Labels are right-aligned with the content of their column, so each must fit between the previous label and the
+/// end of its column.
+#[inline]
+fn label_step(ends: &[usize], coordinates: &[usize], rulers: &Rulers) -> usize {
+    let last = coordinates.iter().max().copied().unwrap_or(0);
+    let fits = |step: usize| {
+        let mut cursor = 0;
+        coordinates
+            .iter()
+            .zip(ends)
+            .filter(|column| column.0.is_multiple_of(step))
+            .all(|column| {
+                let fits = *column.1 >= cursor + column.0.to_string().len() + usize::from(cursor > 0);
+                cursor = *column.1;
+                fits
+            })
+    };
+    let mut magnitude = rulers.step;
+    loop {
+        for factor in [1, 2, 5] {
+            let step = magnitude * factor;
+            if step > last || fits(step) {
+                return step;
+            }
         }
+        magnitude *= 10;
     }
+}
 
-    let ndim = first_shape.len();
+/// Lines of column labels, and optionally tick marks, drawn above a page, labelling every column at the given interval.
+///
+/// Tick marks are always drawn when the interval is wider than the step of the rulers, so that every label stands
+/// above a tick. Labels which would still collide with the previous label are skipped.
+#[inline]
+fn column_ruler(ends: &[usize], coordinates: &[usize], rulers: &Rulers, step: usize) -> Vec<String> {
+    let mut labels = String::new();
+    let mut ticks = String::new();
+    let show_ticks = rulers.ticks || step > rulers.step;
+
+    for (&index, &end) in coordinates.iter().zip(ends) {
+        let is_labelled = index.is_multiple_of(step);
+        let cursor = labels.chars().count();
+
+        let label = index.to_string();
+        if is_labelled && end >= cursor + label.len() + usize::from(cursor > 0) {
+            labels.push_str(&" ".repeat(end - cursor - label.len()));
+            labels.push_str(&label);
+        }
 
-    // Calculate column widths for each array
-    let mut array_widths: Vec<usize> = Vec::with_capacity(arrays.len());
-    for arr in arrays {
-        let width = arr.iter().map(|e| e.to_string().len()).max().unwrap_or(0);
-        array_widths.push(width);
+        if show_ticks && end > 0 {
+            ticks.push_str(&" ".repeat(end - 1 - ticks.len()));
+            ticks.push(if is_labelled { '|' } else { '.' });
+        }
     }
 
-    // Special case for 1D arrays
-    if ndim == 1 {
-        for (arr_idx, arr) in arrays.iter().enumerate() {
-            for (i, elem) in arr.iter().enumerate() {
-                let is_last_in_row = i == arr.len() - 1;
-                element_formatter.format_element(f, elem, array_widths[arr_idx], is_last_in_row)?;
+    if show_ticks { vec![labels, ticks] } else { vec![labels] }
+}
+
+/// Column rulers drawn above the given range of columns of the page of each array.
+///
+/// Every panel shares the same interval between labels, so that their rulers take the same number of lines.
+#[inline]
+fn column_rulers<A: Display, F: ElementFormatter>(
+    pages: &[ArrayView2<'_, A>],
+    columns: &Range<usize>,
+    indent: usize,
+    context: &Context<'_, F>,
+) -> Vec<Vec<String>> {
+    let Some(rulers) = context.rulers.as_ref().filter(|_| context.ndim >= 1) else {
+        return vec![Vec::new(); pages.len()];
+    };
+    let axis = context.column_axis();
+    let coordinates: Vec<usize> = columns.clone().map(|col| context.coordinate(axis, col)).collect();
+    let ends: Vec<Vec<usize>> = pages
+        .iter()
+        .zip(&context.widths)
+        .map(|(full_page, &width)| {
+            let page = full_page.slice(s![.., columns.clone()]);
+            let cells = render_cells(&page, width, context.formatter);
+            column_ends(&page, &cells, width, context.formatter)
+                .iter()
+                .map(|end| end + indent)
+                .collect()
+        })
+        .collect();
+    let step = ends
+        .iter()
+        .map(|panel| label_step(panel, &coordinates, rulers))
+        .max()
+        .unwrap_or(rulers.step);
+    ends.iter()
+        .map(|panel| column_ruler(panel, &coordinates, rulers, step))
+        .collect()
+}
+
+/// Gutter of row labels drawn to the left of a page, with the given number of blank lines after each row.
+#[inline]
+fn row_ruler(coordinates: &[usize], header_height: usize, spacing: usize, rulers: &Rulers) -> Block {
+    let width = coordinates.iter().max().unwrap_or(&0).to_string().len();
+    let labels = coordinates.iter().flat_map(|&row| {
+        let label = if rulers.is_labelled(row) {
+            format!("{row:>width$} ")
+        } else {
+            " ".repeat(width + 1)
+        };
+        once(label).chain(repeat_n(String::new(), spacing))
+    });
+    Block::new(repeat_n(String::new(), header_height).chain(labels).collect())
+}
+
+/// Gutter marking the row crossed by another plane, with the given number of blank lines after each row.
+#[inline]
+fn row_marker(rows: usize, marked: usize, header_height: usize, spacing: usize) -> Block {
+    let markers = (0..rows).flat_map(|row| {
+        let label = if row == marked {
+            format!("{ROW_MARKER} ")
+        } else {
+            "  ".to_owned()
+        };
+        once(label).chain(repeat_n(String::new(), spacing))
+    });
+    Block::new(repeat_n(String::new(), header_height).chain(markers).collect())
+}
+
+/// Render a range of columns of the given page of each array, side by side.
+///
+/// Window edges are only marked on the sides of the range which meet the edge of the page.
+/// Headers and footers are placed above the first and below the last range of the display.
+#[inline]
+fn render_columns<A: Display, F: ElementFormatter>(
+    pages: &[ArrayView2<'_, A>],
+    position: &[usize],
+    columns: &Range<usize>,
+    context: &Context<'_, F>,
+    is_first: bool,
+    is_last: bool,
+) -> Block {
+    let ncols = pages[0].ncols();
+    let (top, bottom) = context.clipping(context.row_axis());
+    let (left, right) = context.clipping(context.column_axis());
+    let indent = if left && columns.start == 0 {
+        format!("{ELLIPSIS} ")
+    } else {
+        String::new()
+    };
+    let blank_indent = " ".repeat(indent.chars().count());
+    let suffix = if right && columns.end == ncols {
+        format!(" {ELLIPSIS}")
+    } else {
+        String::new()
+    };
+
+    let column_labels = column_rulers(pages, columns, blank_indent.len(), context);
+    let mut header_height = 0;
+    let panels: Vec<Block> = pages
+        .iter()
+        .zip(&context.widths)
+        .enumerate()
+        .map(|(index, (full_page, &width))| {
+            let page = full_page.slice(s![.., columns.clone()]);
+            let cells = render_cells(&page, width, context.formatter);
+
+            let mut lines = Vec::new();
+            if is_first {
+                lines.extend(context.headers[index].iter().cloned());
+                lines.resize(context.header_height(), String::new());
             }
+            lines.extend(column_labels[index].iter().cloned());
+            if let Some(marked) = context.marked(context.column_axis()) {
+                let mut marker = String::new();
+                if columns.contains(&marked) {
+                    let end = column_ends(&page, &cells, width, context.formatter)[marked - columns.start] + blank_indent.len();
+                    marker.push_str(&" ".repeat(end.saturating_sub(1)));
+                    marker.push(COLUMN_MARKER);
+                }
+                lines.push(marker);
+            }
+            if top {
+                lines.push(format!("{blank_indent}{VERTICAL_ELLIPSIS}"));
+            }
+            header_height = lines.len();
 
-            if arr_idx < arrays.len() - 1 {
-                element_formatter.write_array_separator(f)?;
+            let mut body: Vec<String> = cells.iter().map(|row| format!("{indent}{}{suffix}", row.concat())).collect();
+            let totals = context.add_margins(index, position, columns, columns.end == ncols, &blank_indent, &mut body);
+            match context.bars.get(index) {
+                Some(bars) => {
+                    let bar_page = page_view(&bars.view(), position).slice_move(s![.., columns.clone()]);
+                    let bar_rows = render_cells(&bar_page, width, context.formatter);
+                    for (line, bar_row) in body.into_iter().zip(bar_rows) {
+                        lines.push(line);
+                        lines.push(format!("{blank_indent}{}", bar_row.concat()));
+                    }
+                }
+                None => lines.append(&mut body),
+            }
+            if bottom {
+                lines.push(format!("{blank_indent}{VERTICAL_ELLIPSIS}"));
             }
+            lines.extend(totals);
+            if is_last {
+                lines.extend(context.footers[index].iter().cloned());
+            }
+
+            // Keep later panels aligned with any decorations
+            if index + 1 < pages.len() {
+                let min_width = context.min_width(index);
+                for line in &mut lines {
+                    line.push_str(&" ".repeat(min_width.saturating_sub(line.chars().count())));
+                }
+            }
+            Block::new(lines)
+        })
+        .collect();
+
+    let spacing = usize::from(!context.bars.is_empty());
+    let mut block = Block::beside(&panels, &context.separator);
+    if let Some(marked) = context.marked(context.row_axis()) {
+        block = block.with_gutter(&row_marker(pages[0].nrows(), marked, header_height, spacing));
+    }
+    match context.rulers.as_ref() {
+        Some(rulers) if context.ndim >= 2 => {
+            let axis = context.row_axis();
+            let coordinates: Vec<usize> = (0..pages[0].nrows()).map(|row| context.coordinate(axis, row)).collect();
+            block.with_gutter(&row_ruler(&coordinates, header_height, spacing, rulers))
+        }
+        _ => block,
+    }
+}
+
+/// Split the columns of a page into consecutive ranges which each fit within the given line width.
+///
+/// Every range holds at least one column, even if that column alone is too wide.
+#[inline]
+fn column_chunks<A: Display, F: ElementFormatter>(
+    pages: &[ArrayView2<'_, A>],
+    line_width: usize,
+    context: &Context<'_, F>,
+) -> Vec<Range<usize>> {
+    let ncols = pages[0].ncols();
+
+    // Width of each column, summed over the side-by-side arrays
+    let mut col_widths = vec![0; ncols];
+    for (page, &width) in pages.iter().zip(&context.widths) {
+        let cells = render_cells(page, width, context.formatter);
+        for (col, total) in col_widths.iter_mut().enumerate() {
+            *total += cells.iter().map(|row| row[col].chars().count()).max().unwrap_or(0);
         }
-        return Ok(());
     }
 
-    // Handle multi-dimensional arrays by recursive traversal
-    recursive_format_arrays(
-        f,
-        arrays,
-        &array_widths,
-        first_shape,
-        0,
-        &mut vec![0; ndim],
-        element_formatter,
-    )
+    // Space taken by row labels, window edge markers and array separators
+    let gutter = match context.rulers {
+        Some(_) if context.ndim >= 2 => {
+            (0..pages[0].nrows())
+                .map(|row| context.coordinate(context.row_axis(), row))
+                .max()
+                .unwrap_or(0)
+                .to_string()
+                .len()
+                + 1
+        }
+        _ => 0,
+    };
+    let (left, right) = context.clipping(context.column_axis());
+    let marker = usize::from(context.marked(context.row_axis()).is_some());
+    let overhead =
+        gutter + 2 * (usize::from(left) + usize::from(right) + marker) + context.separator.chars().count() * (pages.len() - 1);
+
+    let mut chunks = Vec::new();
+    let mut start = 0;
+    let mut used = overhead;
+    for (col, &width) in col_widths.iter().enumerate() {
+        if col > start && used + width > line_width {
+            chunks.push(start..col);
+            start = col;
+            used = overhead;
+        }
+        used += width;
+    }
+    chunks.push(start..ncols);
+    chunks
 }
 
-/// Recursively format multi-dimensional arrays.
+/// Render the page at the given position of each array, side by side.
+///
+/// Pages wider than the wrapping width are split into blocks of columns, each headed by the columns it holds.
+/// The headers of each array are placed above the first page, and the footers below the last.
 #[inline]
-fn recursive_format_arrays<S, D, F>(
-    f: &mut Formatter<'_>,
-    arrays: &[&ArrayBase<S, D>],
-    array_widths: &[usize],
-    shape: &[usize],
-    level: usize,
-    indices: &mut Vec<usize>,
-    element_formatter: &F,
-) -> FmtResult
-where
-    S: Data,
-    S::Elem: Display,
-    D: Dimension,
-    F: ElementFormatter,
-{
-    if level == shape.len() - 2 {
-        // At the row level (second-to-last dimension)
-        for row in 0..shape[level] {
-            indices[level] = row;
-
-            // Process each array at this row
-            for (arr_idx, arr) in arrays.iter().enumerate() {
-                // Print each column in this row
-                for col in 0..shape[level + 1] {
-                    indices[level + 1] = col;
-
-                    // Calculate flattened index
-                    let mut flat_index = 0;
-                    let mut multiplier = 1;
-                    for d in (0..shape.len()).rev() {
-                        flat_index += indices[d] * multiplier;
-                        multiplier *= shape[d];
-                    }
+fn render_page<A: Display, F: ElementFormatter>(
+    pages: &[ArrayView2<'_, A>],
+    position: &[usize],
+    context: &Context<'_, F>,
+    is_first: bool,
+    is_last: bool,
+) -> Block {
+    let chunks = match context.wrap {
+        Some(wrap) if context.ndim >= 1 => column_chunks(pages, wrap.width, context),
+        _ => once(0..pages[0].ncols()).collect(),
+    };
 
-                    let elem = arr.iter().nth(flat_index).unwrap();
-                    let is_last_in_row = col == shape[level + 1] - 1;
-                    element_formatter.format_element(f, elem, array_widths[arr_idx], is_last_in_row)?;
-                }
+    let mut block = Block::default();
+    if let Some(slice_headers) = context.slice_headers.as_ref()
+        && context.ndim >= 3
+    {
+        let mut full_position = vec![None; context.ndim];
+        for (&axis, &index) in context.order.iter().zip(position) {
+            full_position[axis] = Some(context.coordinate(Some(axis), index));
+        }
+        block.lines.push(slice_headers.label(&full_position));
+    }
+
+    for (i, columns) in chunks.iter().enumerate() {
+        if chunks.len() > 1 {
+            if i > 0 {
+                block.lines.push(String::new());
+            }
+            let axis = context.column_axis();
+            let (first, last) = (
+                context.coordinate(axis, columns.start),
+                context.coordinate(axis, columns.end - 1),
+            );
+            block.lines.push(if first == last {
+                format!("Column {first}")
+            } else {
+                format!("Columns {first} through {last}")
+            });
+        }
+        let mut chunk = render_columns(
+            pages,
+            position,
+            columns,
+            context,
+            is_first && i == 0,
+            is_last && i + 1 == chunks.len(),
+        )
+        .lines;
+        if is_first && i == 0 {
+            let rest = chunk.split_off(context.header_height());
+            chunk.append(&mut block.lines);
+            block.lines = chunk;
+            chunk = rest;
+        }
+        block.lines.extend(chunk);
+    }
+    block
+}
+
+/// Number of blank lines placed between two consecutive pages of a single array, given the group of each page axis.
+///
+/// One line is added for each group of leading axes whose position changes.
+#[inline]
+fn page_gap(previous: &[usize], next: &[usize], groups: &[usize]) -> usize {
+    let unchanged = previous.iter().zip(next).take_while(|&(a, b)| a == b).count();
+    match (groups.get(unchanged), groups.last()) {
+        (Some(first), Some(last)) => last - first + 1,
+        _ => 0,
+    }
+}
+
+/// Pool blocks of elements along the last two axes of a view, leaving any page axes untouched.
+#[inline]
+fn pool_view<A>(view: &ArrayViewD<'_, A>, overview: &Overview, numeric: fn(&A) -> f64) -> ArrayD<Rounded> {
+    let shape = view.shape();
+    let ndim = shape.len();
+    let (row_factor, col_factor) = grid_factors(shape, overview);
+    let factor = |axis: usize| match ndim - axis {
+        1 => col_factor,
+        2 => row_factor,
+        _ => 1,
+    };
+
+    let pooled_shape: Vec<usize> = shape
+        .iter()
+        .enumerate()
+        .map(|(axis, len)| len.div_ceil(factor(axis)))
+        .collect();
+    let pooled = ArrayD::from_shape_fn(IxDyn(&pooled_shape), |index| {
+        let block = view.slice_each_axis(|axis| {
+            let n = axis.axis.index();
+            let start = index[n] * factor(n);
+            Slice::from(start..(start + factor(n)).min(axis.len))
+        });
+        let values: Vec<f64> = block.iter().map(numeric).collect();
+        overview.reduction.reduce(&values)
+    });
+
+    // Show every pooled value of the array to the same number of decimal places
+    let precision = Rounded::shared_precision(&pooled, overview.precision);
+    pooled.map(|&value| Rounded { value, precision })
+}
+
+/// Number of rows and columns pooled into each element for arrays of the given shape.
+#[inline]
+fn grid_factors(shape: &[usize], overview: &Overview) -> (usize, usize) {
+    match *shape {
+        [] => (1, 1),
+        [ncols] => (1, overview.factors(1, ncols).1),
+        [.., nrows, ncols] => overview.factors(nrows, ncols),
+    }
+}
 
-                // Space between arrays
-                if arr_idx < arrays.len() - 1 {
-                    element_formatter.write_array_separator(f)?;
+/// Render every page of the views, either stacked or tiled into a mosaic.
+#[inline]
+fn layout<A: Display, F: ElementFormatter>(
+    views: &[ArrayViewD<'_, A>],
+    mosaic: Option<&Mosaic>,
+    numeric: Option<fn(&A) -> f64>,
+    context: &mut Context<'_, F>,
+) -> Vec<String> {
+    context.widths = views.iter().map(element_width).collect();
+    if let (Some(marginals), Some(convert)) = (context.marginals.as_ref(), numeric)
+        && context.ndim >= 2
+    {
+        context.margins = views.iter().map(|view| margins(view, marginals, convert)).collect();
+    }
+
+    // Widen the columns to fit their reductions
+    for (width, margin) in context.widths.iter_mut().zip(&context.margins) {
+        *width = margin.columns.iter().map(String::len).fold(*width, usize::max);
+    }
+
+    if let (Some(data_bars), Some(convert)) = (context.data_bars, numeric) {
+        let bars: Vec<ArrayD<String>> = views
+            .iter()
+            .map(|view| draw_bars(view, data_bars.width.max(1), convert))
+            .collect();
+        if data_bars.placement == BarPlacement::Beside {
+            // Render each element beside its bar, and lay out the combined text instead
+            let combined: Vec<ArrayD<String>> = views
+                .iter()
+                .zip(&bars)
+                .map(|(view, bar)| {
+                    let width = element_width(view);
+                    Zip::from(view)
+                        .and(bar)
+                        .map_collect(|elem, line| format!("{elem:>width$} {line}"))
+                })
+                .collect();
+            let combined_views: Vec<ArrayViewD<'_, String>> = combined.iter().map(ArrayD::view).collect();
+            context.data_bars = None;
+            return layout(&combined_views, mosaic, None, context);
+        }
+        for (width, bar) in context.widths.iter_mut().zip(&bars) {
+            *width = element_width(&bar.view()).max(*width);
+        }
+        context.bars = bars;
+    }
+
+    let mut lines = match mosaic {
+        Some(tiles) if context.ndim >= 3 => tile_pages(views, tiles, context),
+        _ => stack_pages(views, context),
+    };
+
+    // Count the pages clipped by the region, after the array headers and before the footers
+    let (before, after) = context.hidden_pages;
+    if context.ndim >= 3 && after > 0 {
+        let footer_height = context.footers.iter().map(Vec::len).max().unwrap_or(0);
+        lines.insert(lines.len().saturating_sub(footer_height), hidden_line(after, "more"));
+    }
+    if context.ndim >= 3 && before > 0 {
+        lines.insert(context.header_height().min(lines.len()), hidden_line(before, "earlier"));
+    }
+    lines
+}
+
+/// Line counting the given number of pages hidden by the region.
+#[inline]
+fn hidden_line(count: usize, which: &str) -> String {
+    let noun = if count == 1 { "page" } else { "pages" };
+    format!("{ELLIPSIS} {count} {which} {noun}")
+}
+
+/// Render each 2-D page in turn, separated by blank lines.
+#[inline]
+fn stack_pages<A: Display, F: ElementFormatter>(views: &[ArrayViewD<'_, A>], context: &Context<'_, F>) -> Vec<String> {
+    let shape = views[0].shape();
+    let mut lines = Vec::new();
+    let mut previous: Option<Vec<usize>> = None;
+    let positions = indices(IxDyn(&shape[..context.ndim.saturating_sub(2)])).into_iter();
+    let count = positions.len();
+    for (page_index, index) in positions.enumerate() {
+        let position = index.slice().to_vec();
+        if let Some(prev) = previous.as_ref() {
+            let gap = if views.len() == 1 {
+                page_gap(prev, &position, &context.page_groups)
+            } else {
+                1
+            };
+            lines.extend(repeat_n(String::new(), gap));
+        }
+
+        let pages: Vec<ArrayView2<'_, A>> = views.iter().map(|view| page_view(view, &position)).collect();
+        let is_last = page_index + 1 == count;
+        lines.extend(render_page(&pages, &position, context, previous.is_none(), is_last).lines);
+        previous = Some(position);
+    }
+    lines
+}
+
+/// Render the 2-D pages as tiles of a grid, with the first page axis running across and the others running down.
+#[inline]
+fn tile_pages<A: Display, F: ElementFormatter>(
+    views: &[ArrayViewD<'_, A>],
+    mosaic: &Mosaic,
+    context: &Context<'_, F>,
+) -> Vec<String> {
+    let shape = views[0].shape();
+    let across = shape[0];
+    let wrap = mosaic.wrap.unwrap_or(across).max(1);
+    let gutter = " ".repeat(mosaic.gutter);
+
+    let mut lines = Vec::new();
+    let rows = indices(IxDyn(&shape[1..context.ndim - 2])).into_iter();
+    let count = rows.len();
+    for (row_index, index) in rows.enumerate() {
+        let tiles: Vec<Block> = (0..across)
+            .map(|tile| {
+                let position: Vec<usize> = once(tile).chain(index.slice().iter().copied()).collect();
+                let pages: Vec<ArrayView2<'_, A>> = views.iter().map(|view| page_view(view, &position)).collect();
+                let is_first = row_index == 0 && tile == 0;
+                let is_last = row_index + 1 == count && tile + 1 == across;
+                let mut block = render_page(&pages, &position, context, is_first, is_last);
+
+                // Keep the first row of tiles aligned with the headers above the first tile
+                if row_index == 0 && !is_first {
+                    block
+                        .lines
+                        .splice(0..0, repeat_n(String::new(), context.header_height()))
+                        .for_each(drop);
                 }
+                block
+            })
+            .collect();
+
+        for row in tiles.chunks(wrap) {
+            if !lines.is_empty() {
+                lines.extend(repeat_n(String::new(), mosaic.spacing));
             }
-            writeln!(f)?;
+            lines.extend(Block::beside(row, &gutter).lines);
         }
-        return Ok(());
     }
+    lines
+}
 
-    // Process higher dimensions recursively
-    for i in 0..shape[level] {
-        indices[level] = i;
-        recursive_format_arrays(f, arrays, array_widths, shape, level + 1, indices, element_formatter)?;
+/// Render every page of the views after pooling blocks of elements, under a header describing the pooling.
+#[inline]
+fn pool_layout<A, F: ElementFormatter>(
+    views: &[ArrayViewD<'_, A>],
+    overview: &Overview,
+    numeric: fn(&A) -> f64,
+    mosaic: Option<&Mosaic>,
+    context: &mut Context<'_, F>,
+) -> Vec<String> {
+    let pooled: Vec<ArrayD<Rounded>> = views.iter().map(|view| pool_view(view, overview, numeric)).collect();
+    let pooled_views: Vec<ArrayViewD<'_, Rounded>> = pooled.iter().map(ArrayD::view).collect();
 
-        // Add extra newline between blocks except after the last one
-        if i < shape[level] - 1 {
-            writeln!(f)?;
+    let shape = views[0].shape();
+    let (row_factor, col_factor) = grid_factors(shape, overview);
+    if let Some(axis) = context.row_axis() {
+        context.scale[axis] = row_factor;
+    }
+    if let Some(axis) = context.column_axis() {
+        context.scale[axis] = col_factor;
+    }
+
+    let grid = |s: &[usize]| match *s {
+        [.., nrows, ncols] => format!("{nrows}x{ncols}"),
+        [ncols] => format!("1x{ncols}"),
+        [] => "1x1".to_owned(),
+    };
+    let header = format!(
+        "overview: {} of {row_factor}x{col_factor} blocks, {} -> {}",
+        overview.reduction.name(),
+        grid(shape),
+        grid(pooled_views[0].shape())
+    );
+
+    // Place the description of the pooling beneath any array headers
+    let mut lines = layout(&pooled_views, mosaic, Some(|value: &Rounded| value.value), context);
+    lines.insert(context.header_height().min(lines.len()), header);
+    lines
+}
+
+/// Point through which orthogonal planes pass, in original coordinates, if the arrays are 3-D and it is displayed.
+#[inline]
+fn slice_point(ortho_slices: &OrthoSlices, ranges: &[Range<usize>]) -> Option<Vec<usize>> {
+    if ranges.len() != 3 {
+        return None;
+    }
+    let point: Vec<usize> = ortho_slices.point.map_or_else(
+        || ranges.iter().map(|range| range.start + range.len().div_euclid(2)).collect(),
+        |chosen| chosen.to_vec(),
+    );
+    point
+        .iter()
+        .zip(ranges)
+        .all(|(index, range)| range.contains(index))
+        .then_some(point)
+}
+
+/// Render the plane normal to each axis through a point of 3-D views, side by side and each headed by its position.
+///
+/// The headers of each array are placed above the first plane, and the footers below the last.
+#[inline]
+fn slice_layout<A: Display, F: ElementFormatter>(
+    views: &[ArrayViewD<'_, A>],
+    point: &[usize],
+    ortho_slices: &OrthoSlices,
+    overview: Option<&Overview>,
+    numeric: Option<fn(&A) -> f64>,
+    context: &mut Context<'_, F>,
+) -> Vec<String> {
+    let headers = mem::take(&mut context.headers);
+    let footers = mem::take(&mut context.footers);
+    let header_height = headers.iter().map(Vec::len).max().unwrap_or(0);
+    let blank = vec![Vec::new(); views.len()];
+    let data_bars = context.data_bars;
+    let slice_headers = context.slice_headers.take().unwrap_or_default();
+    context.ndim = 2;
+    context.crosshair = ortho_slices.crosshairs.then(|| point.to_vec());
+
+    let mut planes = Vec::new();
+    for normal in 0..3 {
+        let plane_views: Vec<ArrayViewD<'_, A>> = views
+            .iter()
+            .map(|view| view.index_axis(Axis(normal), point[normal] - context.origin[normal]))
+            .collect();
+        context.order = (0..3).filter(|&axis| axis != normal).collect();
+        context.headers = if normal == 0 { headers.clone() } else { blank.clone() };
+        context.footers = if normal == 2 { footers.clone() } else { blank.clone() };
+        context.scale = vec![1; 3];
+        context.margins = Vec::new();
+        context.bars = Vec::new();
+        context.data_bars = data_bars;
+
+        let mut position = vec![None; 3];
+        position[normal] = Some(point[normal]);
+        let mut lines = vec![slice_headers.label(&position)];
+        if normal > 0 {
+            lines.extend(repeat_n(String::new(), header_height));
         }
+        lines.extend(match (overview, numeric) {
+            (Some(pooling), Some(convert)) => pool_layout(&plane_views, pooling, convert, None, context),
+            _ => layout(&plane_views, None, numeric, context),
+        });
+        planes.push(Block::new(lines));
     }
+    Block::beside(&planes, &" ".repeat(ortho_slices.gutter)).lines
+}
 
-    Ok(())
+/// Number of pages hidden before the first displayed page, and elsewhere, when only the given ranges of a region
+/// starting at the given offset of the full shape are displayed.
+#[inline]
+fn hidden_pages(order: &[usize], ranges: &[Range<usize>], offset: &[usize], full_shape: &[usize]) -> (usize, usize) {
+    let page_axes = &order[..order.len().saturating_sub(2)];
+    let (mut before, mut total, mut shown) = (0, 1, 1);
+    for &axis in page_axes {
+        before = before * full_shape[axis] + offset[axis] + ranges[axis].start;
+        total *= full_shape[axis];
+        shown *= ranges[axis].len();
+    }
+    (before, total.saturating_sub(before + shown))
+}
+
+/// Lay out a grid of text cells aligned by a formatter, beside a column of row margins and above a row of column margins
+/// which meet at a corner, separated from the grid by rules in the same way as marginal reductions.
+#[inline]
+pub fn render_grid<F: ElementFormatter>(
+    grid: &ArrayView2<'_, String>,
+    rows: Vec<String>,
+    columns: Vec<String>,
+    corner: String,
+    formatter: &F,
+) -> Vec<String> {
+    let width = rows
+        .iter()
+        .chain(once(&corner))
+        .map(|margin| margin.chars().count())
+        .max()
+        .unwrap_or(0);
+    let margins = Margins {
+        rows: ArrayD::from_shape_vec(IxDyn(&[rows.len()]), rows).unwrap(),
+        columns: ArrayD::from_shape_vec(IxDyn(&[columns.len()]), columns).unwrap(),
+        corners: ArrayD::from_elem(IxDyn(&[]), corner),
+        width,
+    };
+    let mut context = Context {
+        formatter,
+        separator: ArraySeparator(formatter).to_string(),
+        widths: Vec::new(),
+        ndim: 2,
+        order: vec![0, 1],
+        page_groups: Vec::new(),
+        origin: vec![0; 2],
+        clipped: vec![(false, false); 2],
+        hidden_pages: (0, 0),
+        scale: vec![1; 2],
+        rulers: None,
+        slice_headers: None,
+        wrap: None,
+        headers: vec![Vec::new()],
+        footers: vec![Vec::new()],
+        marginals: None,
+        margins: vec![margins],
+        data_bars: None,
+        bars: Vec::new(),
+        crosshair: None,
+    };
+    layout(&[grid.view().into_dyn()], None, None, &mut context)
+}
+
+/// Settings for displaying the given region of the arrays, traversed in the given order of original axes.
+#[inline]
+fn build_context<'f, S, D, F>(
+    display_array: &DisplayArray<'_, S, D, F>,
+    element_formatter: &'f F,
+    order: Vec<usize>,
+    ranges: &[Range<usize>],
+) -> Context<'f, F>
+where
+    S: Data,
+    S::Elem: Display,
+    D: Dimension,
+    F: DisplayMethod + ElementFormatter,
+{
+    // The step is public, so a step of zero can bypass the constructor
+    let rulers = display_array
+        .rulers
+        .or_else(|| (display_array.window.is_some() || display_array.region.is_some()).then(Rulers::new))
+        .map(|found| Rulers {
+            step: found.step.max(1),
+            ..found
+        });
+    let (offset, full_shape) = display_array
+        .region
+        .clone()
+        .unwrap_or_else(|| (vec![0; ranges.len()], display_array.arrays[0].shape().to_vec()));
+    let hidden_pages = hidden_pages(&order, ranges, &offset, &full_shape);
+    Context {
+        formatter: element_formatter,
+        separator: ArraySeparator(element_formatter).to_string(),
+        widths: Vec::new(),
+        ndim: order.len(),
+        page_groups: match display_array.axis_map.as_ref() {
+            Some(axis_map) if display_array.ortho_slices.is_none() => axis_map.page_groups(order.len().saturating_sub(2)),
+            _ => (0..order.len().saturating_sub(2)).collect(),
+        },
+        order,
+        origin: ranges.iter().zip(&offset).map(|(range, start)| start + range.start).collect(),
+        clipped: ranges
+            .iter()
+            .zip(offset.iter().zip(&full_shape))
+            .map(|(range, (start, &len))| (start + range.start > 0, start + range.end < len))
+            .collect(),
+        hidden_pages,
+        scale: vec![1; ranges.len()],
+        wrap: display_array.wrap,
+        headers: display_array
+            .arrays
+            .iter()
+            .map(|arr| {
+                if display_array.header {
+                    vec![describe(arr)]
+                } else {
+                    Vec::new()
+                }
+            })
+            .collect(),
+        footers: display_array
+            .arrays
+            .iter()
+            .map(|arr| match display_array.numeric {
+                Some(numeric) if display_array.stats => summarise(arr, numeric),
+                _ => Vec::new(),
+            })
+            .collect(),
+        marginals: display_array.marginals,
+        margins: Vec::new(),
+        data_bars: display_array.data_bars,
+        bars: Vec::new(),
+        crosshair: None,
+        rulers,
+        slice_headers: display_array
+            .slice_headers
+            .clone()
+            .or_else(|| (rulers.is_some() || display_array.mosaic.is_some_and(|mosaic| mosaic.labels)).then(SliceHeaders::new)),
+    }
 }
 
 /// Implementation of Display for `DisplayArray` with a generic formatter.
@@ -190,8 +1142,87 @@ where
     D: Dimension,
     F: DisplayMethod + ElementFormatter,
 {
-    if display_array.arrays.len() == 1 {
-        return fmt_single_array(display_array.arrays[0], f, element_formatter);
+    let arrays = &display_array.arrays;
+    let Some(first) = arrays.first() else {
+        return Ok(());
+    };
+
+    // Make sure all arrays have the same shape
+    let first_shape = first.shape();
+    for (i, arr) in arrays.iter().enumerate().skip(1) {
+        if arr.shape() != first_shape {
+            return write!(
+                f,
+                "Error: Arrays have different shapes. Array 0 shape: {:?}, Array {} shape: {:?}",
+                first_shape,
+                i,
+                arr.shape()
+            );
+        }
     }
-    fmt_multiple_arrays(&display_array.arrays, f, element_formatter)
+
+    let ndim = first_shape.len();
+    let order = match display_array.axis_map.as_ref() {
+        Some(axis_map) if ndim >= 2 && display_array.ortho_slices.is_none() => match axis_map.permutation(ndim) {
+            Some(order) => order,
+            None => {
+                return write!(
+                    f,
+                    "Error: Invalid axis mapping {axis_map:?} for arrays of shape {first_shape:?}"
+                );
+            }
+        },
+        _ => (0..ndim).collect(),
+    };
+
+    let ranges: Vec<Range<usize>> = first_shape
+        .iter()
+        .enumerate()
+        .map(|(axis, &len)| display_array.window.as_ref().map_or(0..len, |window| window.range(axis, len)))
+        .collect();
+
+    let point = display_array
+        .ortho_slices
+        .as_ref()
+        .map(|ortho_slices| slice_point(ortho_slices, &ranges));
+    if point == Some(None) {
+        return write!(
+            f,
+            "Error: Orthogonal slices require 3-D arrays and a point within the displayed region, but the shape is {first_shape:?}"
+        );
+    }
+
+    let views: Vec<ArrayViewD<'_, S::Elem>> = arrays
+        .iter()
+        .map(|arr| {
+            let mut view = arr.view().into_dyn();
+            view.slice_each_axis_inplace(|axis| Slice::from(ranges[axis.axis.index()].clone()));
+            view.permuted_axes(order.clone())
+        })
+        .collect();
+    let mut context = build_context(display_array, element_formatter, order, &ranges);
+
+    let mosaic = display_array.mosaic.as_ref();
+    let overview = display_array.overview.as_ref();
+    let lines = match (
+        display_array.ortho_slices.as_ref(),
+        point.flatten(),
+        overview,
+        display_array.numeric,
+    ) {
+        (Some(ortho_slices), Some(through), ..) => {
+            slice_layout(&views, &through, ortho_slices, overview, display_array.numeric, &mut context)
+        }
+        (_, _, Some(pooling), Some(numeric)) => pool_layout(&views, pooling, numeric, mosaic, &mut context),
+        _ => layout(&views, mosaic, display_array.numeric, &mut context),
+    };
+
+    write!(f, "{}", lines.join("\n"))?;
+
+    // Multiple arrays of two or more dimensions end each row with a newline
+    if arrays.len() > 1 && ndim >= 2 {
+        writeln!(f)?;
+    }
+
+    Ok(())
 }
@@ -0,0 +1,209 @@
+//! ### `Confusion` Display Method
+//!
+//! This module contains the `Confusion` display method for formatting confusion matrices.
+
+use ndarray::{Array2, ArrayBase, Axis, Data, Ix2};
+use std::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    iter::once,
+};
+
+use crate::{
+    DisplayArray, Numeric, Separated,
+    display_method::{DisplayMethod, block::Block, common::render_grid},
+};
+
+/// Heading of the column of per-class recall.
+const RECALL: &str = "recall";
+
+/// Heading of the row of per-class precision.
+const PRECISION: &str = "precision";
+
+/// Totals that the counts of a confusion matrix are shown as percentages of.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Normalisation {
+    /// Each count is shown as a percentage of its row, the number of samples of the actual class.
+    Rows,
+    /// Each count is shown as a percentage of its column, the number of samples predicted as the class.
+    Columns,
+}
+
+/// Display method for square matrices of counts, with actual classes down the rows and predicted classes across the columns.
+///
+/// The diagonal of correct predictions is bracketed, each row is followed by the recall of its class, each column is
+/// followed by the precision of its class, and the matrix is followed by the overall accuracy. Every count must be a whole
+/// number of zero or more.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Confusion {
+    /// Name of each class, in index order. Classes are labelled by index if empty.
+    pub labels: Vec<String>,
+    /// Optional percentages shown beside each count.
+    pub normalisation: Option<Normalisation>,
+    /// Number of decimal places shown for percentages.
+    pub precision: usize,
+}
+
+impl Confusion {
+    /// Construct a new `Confusion` instance which labels classes by index.
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            labels: Vec::new(),
+            normalisation: None,
+            precision: 1,
+        }
+    }
+
+    /// Set the name of each class, in index order.
+    #[must_use]
+    #[inline]
+    pub fn with_labels<I, L>(mut self, labels: I) -> Self
+    where
+        I: IntoIterator<Item = L>,
+        L: Into<String>,
+    {
+        self.labels = labels.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Show each count beside its percentage of the given total.
+    #[must_use]
+    #[inline]
+    pub const fn with_normalisation(mut self, normalisation: Normalisation) -> Self {
+        self.normalisation = Some(normalisation);
+        self
+    }
+
+    /// Set the number of decimal places shown for percentages.
+    #[must_use]
+    #[inline]
+    pub const fn with_precision(mut self, precision: usize) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    /// Render a part of a whole as a percentage, or a dash if the whole is empty.
+    fn percent(&self, part: f64, whole: f64) -> String {
+        if whole == 0.0 {
+            "-".to_owned()
+        } else {
+            format!("{:.*}%", self.precision, 100.0 * part / whole)
+        }
+    }
+
+    /// Render a confusion matrix, or describe why it cannot be rendered.
+    fn render<S>(&self, arr: &ArrayBase<S, Ix2>) -> Result<Block, String>
+    where
+        S: Data,
+        S::Elem: Display + Numeric,
+    {
+        let n = arr.nrows();
+        if arr.ncols() != n {
+            return Err(format!(
+                "Confusion matrices must be square, but the shape is {:?}",
+                arr.shape()
+            ));
+        }
+        let labels: Vec<String> = if self.labels.is_empty() {
+            (0..n).map(|class| class.to_string()).collect()
+        } else if self.labels.len() == n {
+            self.labels.clone()
+        } else {
+            return Err(format!("{} labels were given for {n} classes", self.labels.len()));
+        };
+
+        if let Some(invalid) = arr.iter().find(|count| count.to_count().is_none()) {
+            return Err(format!(
+                "Confusion matrices must hold whole counts of zero or more, but {invalid} was found"
+            ));
+        }
+        let correct = total(arr.diag().iter())?;
+        let all = total(arr.iter())?;
+
+        let counts = arr.map(Numeric::to_f64);
+        let row_totals = counts.sum_axis(Axis(1));
+        let column_totals = counts.sum_axis(Axis(0));
+
+        // Mark the diagonal with brackets, keeping the numbers of every cell aligned
+        let cell = |row: usize, col: usize| {
+            let count = &arr[(row, col)];
+            let text = match self.normalisation {
+                Some(Normalisation::Rows) => format!("{count} {}", self.percent(counts[(row, col)], row_totals[row])),
+                Some(Normalisation::Columns) => format!("{count} {}", self.percent(counts[(row, col)], column_totals[col])),
+                None => count.to_string(),
+            };
+            if row == col {
+                format!("[{text}]")
+            } else {
+                format!(" {text} ")
+            }
+        };
+
+        // Head the columns with the classes, and lead each row with its class
+        let grid = Array2::from_shape_fn((n + 1, n + 1), |(row, col)| match (row.checked_sub(1), col.checked_sub(1)) {
+            (None, None) => String::new(),
+            (None, Some(class)) => format!(" {} ", labels[class]),
+            (Some(class), None) => labels[class].clone(),
+            (Some(actual), Some(predicted)) => cell(actual, predicted),
+        });
+        let recalls = once(RECALL.to_owned())
+            .chain((0..n).map(|class| self.percent(counts[(class, class)], row_totals[class])))
+            .collect();
+        let precisions = once(PRECISION.to_owned())
+            .chain((0..n).map(|class| format!(" {} ", self.percent(counts[(class, class)], column_totals[class]))))
+            .collect();
+
+        let mut lines = render_grid(&grid.view(), recalls, precisions, String::new(), &Separated);
+        if let Some(last) = lines.last_mut() {
+            last.truncate(last.trim_end().len());
+        }
+        lines.push(format!(
+            "accuracy={} ({correct}/{all})",
+            self.percent(correct.to_f64(), all.to_f64())
+        ));
+        Ok(Block::new(lines))
+    }
+}
+
+/// Exact sum of the given counts, or a description of why it cannot be shown.
+fn total<'a, A, I>(counts: I) -> Result<u128, String>
+where
+    A: Numeric + 'a,
+    I: Iterator<Item = &'a A>,
+{
+    counts
+        .filter_map(Numeric::to_count)
+        .try_fold(0_u128, u128::checked_add)
+        .ok_or_else(|| "The total count is too large to show".to_owned())
+}
+
+impl Default for Confusion {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DisplayMethod for Confusion {}
+
+impl<S> Display for DisplayArray<'_, S, Ix2, Confusion>
+where
+    S: Data,
+    S::Elem: Display + Numeric,
+{
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let method = self.settings();
+        let mut blocks = Vec::new();
+        for arr in &self.arrays {
+            match method.render(arr) {
+                Ok(block) => blocks.push(block),
+                Err(message) => return write!(f, "Error: {message}"),
+            }
+        }
+        write!(f, "{}", Block::beside(&blocks, "  ").lines.join("\n"))
+    }
+}
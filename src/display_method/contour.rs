@@ -0,0 +1,257 @@
+//! ### `Contour` Display Method
+//!
+//! This module contains the `Contour` display method for drawing the iso-lines of 2-D scalar fields.
+
+use ndarray::{Array2, Data, Ix2};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use crate::{
+    DisplayArray, Numeric,
+    display_method::{
+        DisplayMethod,
+        block::Block,
+        canvas::{Canvas, Scale},
+        number::Rounded,
+    },
+};
+
+/// Box-drawing character joining the edges of a cell, indexed by a mask of its top, right, bottom and left edges.
+const GLYPHS: [char; 16] = [
+    ' ', '\u{2575}', '\u{2576}', '\u{2570}', '\u{2577}', '\u{2502}', '\u{256d}', '\u{251c}', '\u{2574}', '\u{256f}',
+    '\u{2500}', '\u{2534}', '\u{256e}', '\u{2524}', '\u{252c}', '\u{253c}',
+];
+
+/// Characters used to mark each level on its lines, in level order.
+const MARKS: &str = "123456789abcdefghijklmnopqrstuvwxyz";
+
+/// Display method that draws the iso-lines of 2-D scalar fields with box-drawing characters, found by marching squares.
+///
+/// Each character is a cell between four neighbouring values, and side-by-side arrays share the same levels.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct Contour {
+    /// Values at which iso-lines are drawn. Levels are spread evenly across the range of the values if empty.
+    pub levels: Vec<f64>,
+    /// Number of evenly spread levels drawn if none are given.
+    pub count: usize,
+    /// Optional number of characters across and lines down, to which the fields are resampled.
+    pub size: Option<(usize, usize)>,
+    /// Whether each level is marked on one of its lines.
+    pub labels: bool,
+    /// Number of decimal places shown in the level and axis labels.
+    pub precision: usize,
+}
+
+impl Contour {
+    /// Construct a new `Contour` instance which draws the given number of levels spread evenly across the values.
+    #[must_use]
+    #[inline]
+    pub const fn new(count: usize) -> Self {
+        Self {
+            levels: Vec::new(),
+            count,
+            size: None,
+            labels: false,
+            precision: 2,
+        }
+    }
+
+    /// Set the values at which iso-lines are drawn.
+    #[must_use]
+    #[inline]
+    pub fn with_levels<I: IntoIterator<Item = f64>>(mut self, levels: I) -> Self {
+        self.levels = levels.into_iter().collect();
+        self
+    }
+
+    /// Resample the fields to the given number of characters across and lines down.
+    #[must_use]
+    #[inline]
+    pub const fn with_size(mut self, width: usize, height: usize) -> Self {
+        self.size = Some((width, height));
+        self
+    }
+
+    /// Set whether each level is marked on one of its lines.
+    #[must_use]
+    #[inline]
+    pub const fn with_labels(mut self, labels: bool) -> Self {
+        self.labels = labels;
+        self
+    }
+
+    /// Set the number of decimal places shown in the level and axis labels.
+    #[must_use]
+    #[inline]
+    pub const fn with_precision(mut self, precision: usize) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    /// Levels drawn across fields spanning the given scale, in increasing order.
+    fn levels(&self, scale: &Scale) -> Vec<f64> {
+        let mut levels = if self.levels.is_empty() {
+            let steps = (self.count + 1).to_f64();
+            (1..=self.count)
+                .map(|step| (step.to_f64() / steps).mul_add(scale.max - scale.min, scale.min))
+                .collect()
+        } else {
+            self.levels.clone()
+        };
+        levels.retain(|level: &f64| level.is_finite());
+        levels.sort_by(f64::total_cmp);
+        levels.dedup();
+        levels
+    }
+
+    /// Draw the iso-lines of a field at each level, framed by its coordinates.
+    fn render(&self, field: &Array2<f64>, levels: &[f64]) -> Vec<String> {
+        let (rows, cols) = field.dim();
+        let (points_down, points_across) = self.size.map_or((rows, cols), |(width, height)| (height + 1, width + 1));
+        let points = if (points_down, points_across) == (rows, cols) {
+            field.clone()
+        } else {
+            resample(field, points_down.max(2), points_across.max(2))
+        };
+        let (height, width) = (points.nrows() - 1, points.ncols() - 1);
+
+        // Join the crossed edges of every cell, noting which level crossed each cell if only one did
+        let mut masks = Array2::<usize>::zeros((height, width));
+        let mut owners = Array2::<Option<usize>>::from_elem((height, width), None);
+        for ((row, col), mask) in masks.indexed_iter_mut() {
+            let corners = [
+                points[(row, col)],
+                points[(row, col + 1)],
+                points[(row + 1, col + 1)],
+                points[(row + 1, col)],
+            ];
+            if corners.iter().any(|corner| !corner.is_finite()) {
+                continue;
+            }
+            let mut crossings = 0;
+            for (index, &level) in levels.iter().enumerate() {
+                let above = corners.map(|corner| corner >= level);
+                let edges = [
+                    above[0] != above[1],
+                    above[1] != above[2],
+                    above[3] != above[2],
+                    above[0] != above[3],
+                ];
+                let crossed = edges
+                    .iter()
+                    .enumerate()
+                    .filter(|edge| *edge.1)
+                    .fold(0, |bits, edge| bits | (1 << edge.0));
+                if crossed != 0 {
+                    *mask |= crossed;
+                    crossings += 1;
+                    owners[(row, col)] = Some(index);
+                }
+            }
+            if crossings > 1 {
+                owners[(row, col)] = None;
+            }
+        }
+
+        let mut glyphs = masks.map(|&mask| GLYPHS[mask]);
+        if self.labels {
+            // Mark each level midway along the straight cells which it alone crosses
+            for (index, mark) in MARKS.chars().enumerate().take(levels.len()) {
+                let candidates: Vec<(usize, usize)> = owners
+                    .indexed_iter()
+                    .filter(|cell| *cell.1 == Some(index) && matches!(glyphs[cell.0], '\u{2500}' | '\u{2502}'))
+                    .map(|cell| cell.0)
+                    .collect();
+                if let Some(&cell) = candidates.get(candidates.len().div_euclid(2)) {
+                    glyphs[cell] = mark;
+                }
+            }
+        }
+
+        let lines: Vec<String> = glyphs.rows().into_iter().map(|row| row.iter().collect()).collect();
+        let x = Scale {
+            min: 0.0,
+            max: cols.saturating_sub(1).to_f64(),
+            precision: 0,
+        };
+        let y = Scale {
+            min: rows.saturating_sub(1).to_f64(),
+            max: 0.0,
+            precision: 0,
+        };
+        Canvas::framed(&lines, width, &x, &y)
+    }
+}
+
+impl Default for Contour {
+    #[inline]
+    fn default() -> Self {
+        Self::new(5)
+    }
+}
+
+impl DisplayMethod for Contour {}
+
+/// Sample a field at the given number of evenly spaced points down and across, interpolating bilinearly.
+#[expect(
+    clippy::as_conversions,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss,
+    reason = "Positions are non-negative and within the field."
+)]
+fn resample(field: &Array2<f64>, rows: usize, cols: usize) -> Array2<f64> {
+    let (last_row, last_col) = (field.nrows() - 1, field.ncols() - 1);
+    let position = |index: usize, count: usize, last: usize| {
+        let exact = index.to_f64() * last.to_f64() / (count - 1).to_f64();
+        let below = (exact.floor() as usize).min(last);
+        (below, (below + 1).min(last), exact - below.to_f64())
+    };
+    Array2::from_shape_fn((rows, cols), |(row, col)| {
+        let (top, bottom, down) = position(row, rows, last_row);
+        let (left, right, across) = position(col, cols, last_col);
+        let upper = (field[(top, right)] - field[(top, left)]).mul_add(across, field[(top, left)]);
+        let lower = (field[(bottom, right)] - field[(bottom, left)]).mul_add(across, field[(bottom, left)]);
+        (lower - upper).mul_add(down, upper)
+    })
+}
+
+impl<S> Display for DisplayArray<'_, S, Ix2, Contour>
+where
+    S: Data,
+    S::Elem: Numeric,
+{
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let method = &self.settings();
+        if let Some(arr) = self.arrays.iter().find(|arr| arr.nrows() < 2 || arr.ncols() < 2) {
+            return write!(
+                f,
+                "Error: Contours require at least two rows and two columns, but the shape is {:?}",
+                arr.shape()
+            );
+        }
+        let fields: Vec<Array2<f64>> = self.arrays.iter().map(|arr| arr.map(Numeric::to_f64)).collect();
+        let scale = Scale::fit(fields.iter().flatten().copied(), method.precision);
+        let levels = method.levels(&scale);
+
+        let panels: Vec<Block> = fields.iter().map(|field| Block::new(method.render(field, &levels))).collect();
+        let mut lines = Block::beside(&panels, "  ").lines;
+        let legend: Vec<String> = levels
+            .iter()
+            .enumerate()
+            .map(|(index, &level)| {
+                let value = Rounded {
+                    value: level,
+                    precision: method.precision,
+                };
+                MARKS
+                    .chars()
+                    .nth(index)
+                    .filter(|_| method.labels)
+                    .map_or_else(|| value.to_string(), |mark| format!("{mark}={value}"))
+            })
+            .collect();
+        lines.push(format!("levels: {}", legend.join("  ")));
+        write!(f, "{}", lines.join("\n"))
+    }
+}
@@ -0,0 +1,72 @@
+//! ### Array descriptions
+//!
+//! This module contains functions which describe the type, shape and memory layout of an array.
+
+use ndarray::{ArrayBase, Data, Dimension};
+use std::any::type_name;
+
+/// Shorten a type name by removing the module path from each of its components.
+#[inline]
+fn short_type_name(name: &str) -> String {
+    let mut short = String::new();
+    let mut token_start = 0;
+    let mut chars = name.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == ':' && chars.peek() == Some(&':') {
+            let _colon = chars.next();
+            short.truncate(token_start);
+        } else {
+            short.push(c);
+            if !(c.is_alphanumeric() || c == '_') {
+                token_start = short.len();
+            }
+        }
+    }
+    short
+}
+
+/// Name of the `ndarray` type alias matching the given storage type, such as `Array` or `ArrayView`.
+#[inline]
+fn storage_name<S>() -> &'static str {
+    let storage = type_name::<S>();
+    if storage.contains("OwnedArcRepr") {
+        "ArcArray"
+    } else if storage.contains("OwnedRepr") {
+        "Array"
+    } else if storage.contains("CowRepr") {
+        "CowArray"
+    } else if storage.contains("ViewRepr<&mut") {
+        "ArrayViewMut"
+    } else if storage.contains("ViewRepr") {
+        "ArrayView"
+    } else {
+        "ArrayBase"
+    }
+}
+
+/// Write a list of numbers as a tuple, such as `(3, 4)`.
+#[inline]
+pub fn tuple<T: ToString>(values: &[T]) -> String {
+    let items: Vec<String> = values.iter().map(ToString::to_string).collect();
+    format!("({})", items.join(", "))
+}
+
+/// Describe the type, shape, strides and memory layout of an array, such as `Array2<f64> shape=(3, 4) strides=(4, 1) C-contiguous`.
+#[inline]
+pub fn describe<S: Data, D: Dimension>(arr: &ArrayBase<S, D>) -> String {
+    let dimension = D::NDIM.map_or_else(|| "D".to_owned(), |ndim| ndim.to_string());
+    let layout = if arr.is_standard_layout() {
+        "C-contiguous"
+    } else if arr.t().is_standard_layout() {
+        "F-contiguous"
+    } else {
+        "non-contiguous"
+    };
+    format!(
+        "{}{dimension}<{}> shape={} strides={} {layout}",
+        storage_name::<S>(),
+        short_type_name(type_name::<S::Elem>()),
+        tuple(arr.shape()),
+        tuple(arr.strides()),
+    )
+}
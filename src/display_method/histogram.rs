@@ -0,0 +1,277 @@
+//! ### `Histogram` Display Method
+//!
+//! This module contains the `Histogram` display method for drawing the distribution of the elements of arrays.
+
+use ndarray::{Data, Dimension};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use crate::{
+    DisplayArray, Numeric,
+    display_method::{
+        DisplayMethod,
+        bars::{horizontal_bar, vertical_bar},
+        block::Block,
+        canvas::{Canvas, Scale},
+        number::Rounded,
+    },
+};
+
+/// Direction in which the bars of a histogram grow.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Orientation {
+    /// One line per bin, with bars growing to the right.
+    Horizontal,
+    /// One column per bin, with bars growing upwards.
+    Vertical,
+}
+
+/// Display method that counts the elements of each array into equal-width bins and draws a bar for each bin.
+///
+/// Side-by-side arrays share the same bins and the same bar scale.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct Histogram {
+    /// Number of bins.
+    pub bins: usize,
+    /// Lower and upper edges of the binned range, or `None` to span the finite elements.
+    pub range: Option<(f64, f64)>,
+    /// Whether bar lengths are proportional to the logarithm of the counts.
+    pub log: bool,
+    /// Direction in which the bars grow.
+    pub orientation: Orientation,
+    /// Number of characters spanned by the longest bar.
+    pub length: usize,
+    /// Number of decimal places shown for bin edges.
+    pub precision: usize,
+}
+
+impl Histogram {
+    /// Construct a new `Histogram` instance with the given number of bins, drawn horizontally.
+    #[must_use]
+    #[inline]
+    pub const fn new(bins: usize) -> Self {
+        Self {
+            bins: if bins == 0 { 1 } else { bins },
+            range: None,
+            log: false,
+            orientation: Orientation::Horizontal,
+            length: 40,
+            precision: 2,
+        }
+    }
+
+    /// Set the lower and upper edges of the binned range. Elements outside the range are excluded.
+    #[must_use]
+    #[inline]
+    pub const fn with_range(mut self, min: f64, max: f64) -> Self {
+        self.range = Some((min, max));
+        self
+    }
+
+    /// Set whether bar lengths are proportional to the logarithm of the counts.
+    #[must_use]
+    #[inline]
+    pub const fn with_log(mut self, log: bool) -> Self {
+        self.log = log;
+        self
+    }
+
+    /// Set the direction in which the bars grow.
+    #[must_use]
+    #[inline]
+    pub const fn with_orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Set the number of characters spanned by the longest bar.
+    #[must_use]
+    #[inline]
+    pub const fn with_length(mut self, length: usize) -> Self {
+        self.length = if length == 0 { 1 } else { length };
+        self
+    }
+
+    /// Set the number of decimal places shown for bin edges.
+    #[must_use]
+    #[inline]
+    pub const fn with_precision(mut self, precision: usize) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    /// Length of the bar of a count, in eighths of a character, relative to the largest count.
+    #[expect(
+        clippy::as_conversions,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        reason = "The length is rounded and bounded by the length of the longest bar."
+    )]
+    fn eighths(&self, count: usize, largest: usize) -> usize {
+        let scaled = |n: usize| if self.log { n.to_f64().ln_1p() } else { n.to_f64() };
+        if largest == 0 {
+            0
+        } else {
+            (scaled(count) / scaled(largest) * (self.length * 8).to_f64()).round() as usize
+        }
+    }
+
+    /// Label of the bin with the given index, closed on the right for the last bin.
+    fn edges(&self, scale: &Scale, bin: usize) -> String {
+        let edge = |index: usize| Rounded {
+            value: scale.min + (scale.max - scale.min) * index.to_f64() / self.bins.to_f64(),
+            precision: self.precision,
+        };
+        let close = if bin + 1 == self.bins { ']' } else { ')' };
+        format!("[{}, {}{close}", edge(bin), edge(bin + 1))
+    }
+
+    /// Draw the counts of each array as rows of bars, beside a shared column of bin edges.
+    fn horizontal(&self, scale: &Scale, counts: &[Vec<usize>], largest: usize) -> Vec<String> {
+        let mut columns = vec![Block::new((0..self.bins).map(|bin| self.edges(scale, bin)).collect())];
+        for array_counts in counts {
+            let width = largest.to_string().len();
+            columns.push(Block::new(
+                array_counts
+                    .iter()
+                    .map(|&count| format!("{count:>width$} {}", horizontal_bar(self.eighths(count, largest))))
+                    .collect(),
+            ));
+        }
+        let edge_width = columns[0].width();
+        for line in &mut columns[0].lines {
+            *line = format!("{line:>edge_width$}");
+        }
+        Block::beside(&columns, "  ").lines
+    }
+
+    /// Label of each line of vertical bars, from the top, for counts up to the given largest.
+    ///
+    /// Each tick of the canvas is moved to the line nearest the whole count closest to it, and is dropped if that count
+    /// is already labelled, so that small counts are not labelled repeatedly.
+    #[expect(
+        clippy::as_conversions,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        reason = "The line is rounded and clamped to the bars."
+    )]
+    fn count_labels(&self, largest: usize) -> Vec<Option<String>> {
+        let last = self.length.saturating_sub(1).max(1).to_f64();
+        let top = if self.log {
+            largest.to_f64().ln_1p()
+        } else {
+            largest.to_f64()
+        };
+        let count_at = |line: usize| {
+            let scaled = top * (1.0 - line.to_f64() / last);
+            if self.log { scaled.exp_m1() } else { scaled }.round()
+        };
+        let line_of = |count: f64| {
+            let scaled = if self.log { count.ln_1p() } else { count };
+            if top > 0.0 {
+                ((1.0 - scaled / top) * last).round().clamp(0.0, last) as usize
+            } else {
+                self.length.saturating_sub(1)
+            }
+        };
+
+        let mut labels = vec![None; self.length];
+        let mut labelled = Vec::new();
+        for line in Canvas::tick_rows(self.length) {
+            let count = count_at(line);
+            let nearest = line_of(count);
+            if !labelled.contains(&count) && labels[nearest].is_none() {
+                labels[nearest] = Some(count.to_string());
+                labelled.push(count);
+            }
+        }
+        labels
+    }
+
+    /// Draw the counts of each array as columns of bars, framed by count and edge labels, side by side.
+    fn vertical(&self, scale: &Scale, counts: &[Vec<usize>], largest: usize) -> Vec<String> {
+        let frames: Vec<Block> = counts
+            .iter()
+            .map(|array_counts| {
+                let rows: Vec<String> = (0..self.length)
+                    .rev()
+                    .map(|line| {
+                        array_counts
+                            .iter()
+                            .map(|&count| vertical_bar(self.eighths(count, largest), line))
+                            .collect()
+                    })
+                    .collect();
+                Block::new(Canvas::labelled(&rows, self.bins, scale, &self.count_labels(largest)))
+            })
+            .collect();
+        Block::beside(&frames, "  ").lines
+    }
+}
+
+impl Default for Histogram {
+    #[inline]
+    fn default() -> Self {
+        Self::new(10)
+    }
+}
+
+impl DisplayMethod for Histogram {}
+
+impl<S, D> Display for DisplayArray<'_, S, D, Histogram>
+where
+    S: Data,
+    S::Elem: Numeric,
+    D: Dimension,
+{
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        // The fields are public, so a bin count or length of zero can bypass the builders
+        let settings = self.settings();
+        let method = &Histogram {
+            bins: settings.bins.max(1),
+            length: settings.length.max(1),
+            ..settings
+        };
+        let values: Vec<Vec<f64>> = self
+            .arrays
+            .iter()
+            .map(|arr| arr.iter().map(Numeric::to_f64).collect())
+            .collect();
+        let scale = match method.range {
+            Some((min, max)) if min < max => Scale {
+                min,
+                max,
+                precision: method.precision,
+            },
+            Some(range) => return write!(f, "Error: Invalid histogram range {range:?}"),
+            None => Scale::fit(values.iter().flatten().copied(), method.precision),
+        };
+
+        let mut excluded = 0;
+        let counts: Vec<Vec<usize>> = values
+            .iter()
+            .map(|array_values| {
+                let mut array_counts = vec![0; method.bins];
+                for &value in array_values {
+                    if value.is_finite() && (scale.min..=scale.max).contains(&value) {
+                        array_counts[scale.bin(value, method.bins)] += 1;
+                    } else {
+                        excluded += 1;
+                    }
+                }
+                array_counts
+            })
+            .collect();
+        let largest = counts.iter().flatten().copied().max().unwrap_or(0);
+
+        let lines = match method.orientation {
+            Orientation::Horizontal => method.horizontal(&scale, &counts, largest),
+            Orientation::Vertical => method.vertical(&scale, &counts, largest),
+        };
+        let note = (excluded > 0).then(|| format!("{excluded} elements outside the range or not finite were excluded"));
+        let all: Vec<String> = lines.into_iter().chain(note).collect();
+        write!(f, "{}", all.join("\n"))
+    }
+}
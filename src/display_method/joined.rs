@@ -11,7 +11,7 @@ use crate::{
 };
 
 /// Display method that prints each element without any separator.
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, Default)]
 #[non_exhaustive]
 pub struct Joined;
 
@@ -0,0 +1,138 @@
+//! ### `LineChart` Display Method
+//!
+//! This module contains the `LineChart` display method for plotting 1-D arrays as lines on a Braille canvas.
+
+use ndarray::{Data, Ix1};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use crate::{
+    DisplayArray, Numeric,
+    display_method::{
+        DisplayMethod,
+        canvas::{Canvas, Scale, paint},
+    },
+};
+
+/// Display method that plots each 1-D array as a line against its indices, overlaid on shared axes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct LineChart {
+    /// Number of characters across the plotting area.
+    pub width: usize,
+    /// Number of lines down the plotting area.
+    pub height: usize,
+    /// Name of each series shown in the legend. Series are named by index if empty.
+    pub labels: Vec<String>,
+    /// Whether each series is drawn in its own colour.
+    pub colours: bool,
+    /// Number of decimal places shown in the value labels.
+    pub precision: usize,
+}
+
+impl LineChart {
+    /// Construct a new `LineChart` instance with a plotting area of the given number of characters across and lines down.
+    #[must_use]
+    #[inline]
+    pub const fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            labels: Vec::new(),
+            colours: true,
+            precision: 2,
+        }
+    }
+
+    /// Set the name of each series shown in the legend.
+    #[must_use]
+    #[inline]
+    pub fn with_labels<I, L>(mut self, labels: I) -> Self
+    where
+        I: IntoIterator<Item = L>,
+        L: Into<String>,
+    {
+        self.labels = labels.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Set whether each series is drawn in its own colour, using ANSI escape sequences.
+    #[must_use]
+    #[inline]
+    pub const fn with_colours(mut self, colours: bool) -> Self {
+        self.colours = colours;
+        self
+    }
+
+    /// Set the number of decimal places shown in the value labels.
+    #[must_use]
+    #[inline]
+    pub const fn with_precision(mut self, precision: usize) -> Self {
+        self.precision = precision;
+        self
+    }
+}
+
+impl Default for LineChart {
+    #[inline]
+    fn default() -> Self {
+        Self::new(60, 15)
+    }
+}
+
+impl DisplayMethod for LineChart {}
+
+impl<S> Display for DisplayArray<'_, S, Ix1, LineChart>
+where
+    S: Data,
+    S::Elem: Numeric,
+{
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let method = &self.settings();
+        let series: Vec<Vec<f64>> = self
+            .arrays
+            .iter()
+            .map(|arr| arr.iter().map(Numeric::to_f64).collect())
+            .collect();
+        let length = series.iter().map(Vec::len).max().unwrap_or(0);
+        if length == 0 {
+            return write!(f, "Error: Line charts require at least one value");
+        }
+
+        let x = Scale::fit([0, length.saturating_sub(1)].iter().map(Numeric::to_f64), 0);
+        let y = Scale::fit(series.iter().flatten().copied(), method.precision);
+        let mut canvas = Canvas::new(method.width, method.height, method.colours);
+        let (dot_width, dot_height) = (canvas.dot_width(), canvas.dot_height());
+
+        // Join consecutive finite values, leaving gaps at values which are not finite
+        for (index, values) in series.iter().enumerate() {
+            let mut previous = None;
+            for (i, &value) in values.iter().enumerate() {
+                if !value.is_finite() {
+                    previous = None;
+                    continue;
+                }
+                let point = (x.step(i.to_f64(), dot_width), dot_height - 1 - y.step(value, dot_height));
+                canvas.line(previous.unwrap_or(point), point, index);
+                previous = Some(point);
+            }
+        }
+
+        let mut lines = Canvas::framed(&canvas.rows(), canvas.width, &x, &y);
+        if series.len() > 1 || !method.labels.is_empty() {
+            let entries: Vec<String> = (0..series.len())
+                .map(|index| {
+                    let marker = if method.colours {
+                        paint("\u{28ff}", index)
+                    } else {
+                        "\u{28ff}".to_owned()
+                    };
+                    let name = method.labels.get(index).cloned().unwrap_or_else(|| index.to_string());
+                    format!("{marker} {name}")
+                })
+                .collect();
+            lines.push(entries.join("  "));
+        }
+        write!(f, "{}", lines.join("\n"))
+    }
+}
@@ -2,17 +2,47 @@
 //!
 //! This module contains the `DisplayMethod` trait and its implementations for various display methods.
 
+mod arrows;
+mod bars;
+mod block;
+mod box_plot;
+mod canvas;
 mod comma_separated;
 mod common;
+mod confusion;
+mod contour;
+mod describe;
 mod double_joined;
 mod formatter;
+mod histogram;
 mod joined;
+mod line_chart;
+mod number;
+mod scatter;
 mod separated;
+mod sparkline;
+mod sparse;
+mod spy;
+mod stats;
+mod surface;
+mod voxels;
 
+pub use arrows::{Arrows, Magnitude};
+pub use box_plot::{BoxPlot, Groups};
 pub use comma_separated::CommaSeparated;
+pub use confusion::{Confusion, Normalisation};
+pub use contour::Contour;
 pub use double_joined::DoubleJoined;
+pub use histogram::{Histogram, Orientation};
 pub use joined::Joined;
+pub use line_chart::LineChart;
+pub use scatter::{PointEncoding, Scatter};
 pub use separated::Separated;
+pub use sparkline::Sparkline;
+pub use sparse::{EntryOrder, Sparse};
+pub use spy::Spy;
+pub use surface::{Projection, Surface};
+pub use voxels::{Shading, Voxels};
 
 /// Common trait for display methods.
 pub trait DisplayMethod {}
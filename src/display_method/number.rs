@@ -0,0 +1,39 @@
+//! ### `Rounded`
+//!
+//! This module contains the `Rounded` type, which displays computed values such as pooled blocks and summary statistics.
+
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+/// Computed value displayed to a fixed number of decimal places, without the sign of a value which rounds to zero.
+#[derive(Debug, Clone, Copy)]
+pub struct Rounded {
+    /// The value to display.
+    pub value: f64,
+    /// Number of decimal places shown.
+    pub precision: usize,
+}
+
+impl Rounded {
+    /// Number of decimal places which shows the given values alike: none if they are all integral, else the precision.
+    ///
+    /// Values displayed together, such as a column of totals, share this precision so that their decimals line up.
+    #[inline]
+    pub fn shared_precision<'v, I: IntoIterator<Item = &'v f64>>(values: I, precision: usize) -> usize {
+        if values.into_iter().all(|value| value.fract() == 0.0 || !value.is_finite()) {
+            0
+        } else {
+            precision
+        }
+    }
+}
+
+impl Display for Rounded {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let text = format!("{:.*}", self.precision, self.value);
+        match text.strip_prefix('-') {
+            Some(magnitude) if magnitude.chars().all(|c| c == '0' || c == '.') => write!(f, "{magnitude}"),
+            _ => write!(f, "{text}"),
+        }
+    }
+}
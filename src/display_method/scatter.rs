@@ -0,0 +1,222 @@
+//! ### `Scatter` Display Method
+//!
+//! This module contains the `Scatter` display method for plotting the rows of `(N, 2)` arrays as points.
+
+use ndarray::{Data, Ix2};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use crate::{
+    DisplayArray, Numeric,
+    display_method::{
+        DisplayMethod,
+        canvas::{Canvas, Scale, paint},
+    },
+};
+
+/// Shades drawn over cells holding many points, from sparse to dense.
+const SHADES: [char; 4] = ['\u{2591}', '\u{2592}', '\u{2593}', '\u{2588}'];
+
+/// Number of colour classes used to show the third column.
+const CLASSES: usize = 6;
+
+/// Use made of the third column of each point.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PointEncoding {
+    /// Colour each point by the class of its value, with the class ranges listed in the legend.
+    Colour,
+    /// Draw each point as a square of one to three dots across, growing with its value.
+    Size,
+}
+
+/// Display method that plots the first two columns of each row as a point on a Braille canvas with auto-scaled axes.
+///
+/// Points of side-by-side arrays are overlaid in their own colours.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Scatter {
+    /// Number of characters across the plotting area.
+    pub width: usize,
+    /// Number of lines down the plotting area.
+    pub height: usize,
+    /// Optional use of the third column of each point.
+    pub encoding: Option<PointEncoding>,
+    /// Number of points in a character at which it is shaded by density instead of drawn as dots, if any.
+    pub density: Option<usize>,
+    /// Whether points are coloured, using ANSI escape sequences.
+    pub colours: bool,
+    /// Number of decimal places shown in the axis labels.
+    pub precision: usize,
+}
+
+impl Scatter {
+    /// Construct a new `Scatter` instance with a plotting area of the given number of characters across and lines down.
+    #[must_use]
+    #[inline]
+    pub const fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            encoding: None,
+            density: None,
+            colours: true,
+            precision: 2,
+        }
+    }
+
+    /// Use the third column of each point to set its colour or size.
+    #[must_use]
+    #[inline]
+    pub const fn with_encoding(mut self, encoding: PointEncoding) -> Self {
+        self.encoding = Some(encoding);
+        self
+    }
+
+    /// Shade characters holding at least the given number of points by their density, relative to the densest.
+    #[must_use]
+    #[inline]
+    pub const fn with_density(mut self, threshold: usize) -> Self {
+        self.density = Some(if threshold == 0 { 1 } else { threshold });
+        self
+    }
+
+    /// Set whether points are coloured, using ANSI escape sequences.
+    #[must_use]
+    #[inline]
+    pub const fn with_colours(mut self, colours: bool) -> Self {
+        self.colours = colours;
+        self
+    }
+
+    /// Set the number of decimal places shown in the axis labels.
+    #[must_use]
+    #[inline]
+    pub const fn with_precision(mut self, precision: usize) -> Self {
+        self.precision = precision;
+        self
+    }
+}
+
+impl Default for Scatter {
+    #[inline]
+    fn default() -> Self {
+        Self::new(60, 20)
+    }
+}
+
+impl DisplayMethod for Scatter {}
+
+/// A point to be plotted.
+struct Point {
+    /// Horizontal position.
+    x: f64,
+    /// Vertical position.
+    y: f64,
+    /// Optional value of the third column.
+    z: Option<f64>,
+    /// Index of the array holding the point.
+    array: usize,
+}
+
+impl<S> Display for DisplayArray<'_, S, Ix2, Scatter>
+where
+    S: Data,
+    S::Elem: Numeric,
+{
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let method = &self.settings();
+        let mut points = Vec::new();
+        for (array, arr) in self.arrays.iter().enumerate() {
+            if arr.ncols() < 2 {
+                return write!(
+                    f,
+                    "Error: Scatter plots require at least two columns, but the shape is {:?}",
+                    arr.shape()
+                );
+            }
+            points.extend(arr.rows().into_iter().map(|row| Point {
+                x: row[0].to_f64(),
+                y: row[1].to_f64(),
+                z: row.get(2).map(Numeric::to_f64),
+                array,
+            }));
+        }
+        points.retain(|point| point.x.is_finite() && point.y.is_finite());
+
+        let x = Scale::fit(points.iter().map(|point| point.x), method.precision);
+        let y = Scale::fit(points.iter().map(|point| point.y), method.precision);
+        let z = Scale::fit(points.iter().filter_map(|point| point.z), method.precision);
+        let mut canvas = Canvas::new(method.width, method.height, method.colours);
+        let (dot_width, dot_height) = (canvas.dot_width(), canvas.dot_height());
+        let mut counts = vec![0_usize; canvas.width * canvas.height];
+
+        for point in &points {
+            let (dot_x, dot_y) = (x.step(point.x, dot_width), dot_height - 1 - y.step(point.y, dot_height));
+            let (series, size) = match (method.encoding, point.z) {
+                (Some(PointEncoding::Colour), Some(value)) => (z.bin(value, CLASSES), 1),
+                (Some(PointEncoding::Size), Some(value)) => (point.array, 1 + z.bin(value, 3)),
+                _ => (point.array, 1),
+            };
+            for dx in 0..size {
+                for dy in 0..size {
+                    canvas.set(
+                        (dot_x + dx).saturating_sub(size.div_euclid(2)),
+                        (dot_y + dy).saturating_sub(size.div_euclid(2)),
+                        series,
+                    );
+                }
+            }
+            counts[dot_y.div_euclid(4) * canvas.width + dot_x.div_euclid(2)] += 1;
+        }
+
+        // Replace the dots of crowded characters with shades, relative to the most crowded
+        if let Some(threshold) = method.density {
+            let densest = counts.iter().copied().max().unwrap_or(0).max(threshold);
+            for (cell, &count) in counts.iter().enumerate() {
+                if count >= threshold {
+                    let level = ((count - threshold) * (SHADES.len() - 1)).div_euclid((densest - threshold).max(1));
+                    let series = canvas.series[cell].unwrap_or(0);
+                    canvas.put(
+                        cell.rem_euclid(canvas.width),
+                        cell.div_euclid(canvas.width),
+                        SHADES[level],
+                        series,
+                    );
+                }
+            }
+        }
+
+        let mut lines = Canvas::framed(&canvas.rows(), canvas.width, &x, &y);
+        let legend: Vec<String> = match method.encoding {
+            Some(PointEncoding::Colour) if points.iter().any(|point| point.z.is_some()) => (0..CLASSES)
+                .map(|class| {
+                    let fraction = |step: f64| (class.to_f64() + step) / CLASSES.to_f64();
+                    format!(
+                        "{} {}..{}",
+                        marker(class, method.colours),
+                        z.label(fraction(0.0)),
+                        z.label(fraction(1.0))
+                    )
+                })
+                .collect(),
+            _ if self.arrays.len() > 1 => (0..self.arrays.len())
+                .map(|array| format!("{} {array}", marker(array, method.colours)))
+                .collect(),
+            _ => Vec::new(),
+        };
+        if !legend.is_empty() {
+            lines.push(legend.join("  "));
+        }
+        write!(f, "{}", lines.join("\n"))
+    }
+}
+
+/// Legend marker of the given series.
+fn marker(series: usize, colours: bool) -> String {
+    if colours {
+        paint("\u{28ff}", series)
+    } else {
+        "\u{28ff}".to_owned()
+    }
+}
@@ -0,0 +1,227 @@
+//! ### `Sparkline` Display Method
+//!
+//! This module contains the `Sparkline` display method for drawing series as lines of block glyphs.
+
+use ndarray::{ArrayView1, Axis, Data, Dimension};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use crate::{
+    DisplayArray, Numeric, Reduction, Wrap,
+    display_method::{DisplayMethod, bars::LOWER_EIGHTHS, describe::tuple, number::Rounded},
+};
+
+/// Display method that draws each 1-D array, or each row of a 2-D array, as a line of block glyphs.
+///
+/// Each sparkline is scaled to its own range, and series longer than the target width are resampled to fit.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Sparkline {
+    /// Maximum number of glyphs in each sparkline, or `None` to fit the terminal width.
+    pub width: Option<usize>,
+    /// Reduction applied to the groups of values combined when resampling.
+    pub reduction: Reduction,
+    /// Whether each sparkline is preceded by the index of its row (prefixed by that of its array if there are several), or
+    /// of its array for 1-D arrays.
+    pub labels: bool,
+    /// Whether each sparkline is followed by the minimum and maximum of its values.
+    pub annotations: bool,
+}
+
+impl Sparkline {
+    /// Construct a new `Sparkline` instance which fits the terminal width, averaging values when resampling.
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            width: None,
+            reduction: Reduction::Mean,
+            labels: false,
+            annotations: false,
+        }
+    }
+
+    /// Set the maximum number of glyphs in each sparkline.
+    #[must_use]
+    #[inline]
+    pub const fn with_width(mut self, width: usize) -> Self {
+        self.width = Some(if width == 0 { 1 } else { width });
+        self
+    }
+
+    /// Set the reduction applied to the groups of values combined when resampling.
+    #[must_use]
+    #[inline]
+    pub const fn with_reduction(mut self, reduction: Reduction) -> Self {
+        self.reduction = reduction;
+        self
+    }
+
+    /// Set whether each sparkline is preceded by the index of its row, or of its array for 1-D arrays.
+    ///
+    /// Rows of side-by-side 2-D arrays are labelled by the index of their array and then of the row, such as `(1, 0)`.
+    #[must_use]
+    #[inline]
+    pub const fn with_labels(mut self, labels: bool) -> Self {
+        self.labels = labels;
+        self
+    }
+
+    /// Set whether each sparkline is followed by the minimum and maximum of its values.
+    #[must_use]
+    #[inline]
+    pub const fn with_annotations(mut self, annotations: bool) -> Self {
+        self.annotations = annotations;
+        self
+    }
+}
+
+impl Default for Sparkline {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DisplayMethod for Sparkline {}
+
+/// Combine consecutive groups of values so that no more than the given number remain.
+#[inline]
+pub fn resample(values: &[f64], width: usize, reduction: Reduction) -> Vec<f64> {
+    if values.len() <= width {
+        return values.to_vec();
+    }
+    (0..width)
+        .map(|bucket| {
+            let start = (bucket * values.len()).div_euclid(width);
+            let end = ((bucket + 1) * values.len()).div_euclid(width);
+            reduction.reduce(&values[start..end])
+        })
+        .collect()
+}
+
+/// Draw values as a line of block glyphs, scaled from the smallest to the largest finite value.
+///
+/// Values which are not finite are left blank.
+#[expect(
+    clippy::as_conversions,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss,
+    reason = "The glyph index is rounded and clamped to the available glyphs."
+)]
+fn draw(values: &[f64]) -> String {
+    let (min, max) = range(values);
+    values
+        .iter()
+        .map(|&value| {
+            if !value.is_finite() {
+                ' '
+            } else if max > min {
+                LOWER_EIGHTHS[((value - min) / (max - min) * 7.0).round() as usize]
+            } else {
+                LOWER_EIGHTHS[3]
+            }
+        })
+        .collect()
+}
+
+/// Smallest and largest finite values, or zeros if there are none.
+fn range(values: &[f64]) -> (f64, f64) {
+    values
+        .iter()
+        .copied()
+        .filter(|value| value.is_finite())
+        .fold(None, |range: Option<(f64, f64)>, value| {
+            Some(range.map_or((value, value), |(min, max)| (min.min(value), max.max(value))))
+        })
+        .unwrap_or((0.0, 0.0))
+}
+
+/// A single series to be drawn, with its label.
+struct Series {
+    /// Label placed before the sparkline.
+    label: String,
+    /// Values of the series.
+    values: Vec<f64>,
+}
+
+impl<S, D> Display for DisplayArray<'_, S, D, Sparkline>
+where
+    S: Data,
+    S::Elem: Numeric,
+    D: Dimension,
+{
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let method = self.settings();
+        let to_values = |lane: ArrayView1<'_, S::Elem>| lane.iter().map(Numeric::to_f64).collect::<Vec<f64>>();
+        let mut series = Vec::new();
+        for (index, arr) in self.arrays.iter().enumerate() {
+            let view = arr.view().into_dyn();
+            match view.ndim() {
+                1 => series.push(Series {
+                    label: index.to_string(),
+                    values: to_values(view.into_dimensionality().unwrap()),
+                }),
+                2 => series.extend(view.axis_iter(Axis(0)).enumerate().map(|(row, lane)| Series {
+                    label: if self.arrays.len() > 1 {
+                        tuple(&[index, row])
+                    } else {
+                        row.to_string()
+                    },
+                    values: to_values(lane.into_dimensionality().unwrap()),
+                })),
+                ndim => {
+                    return write!(
+                        f,
+                        "Error: Sparklines require 1-D or 2-D arrays, but the arrays have {ndim} dimensions"
+                    );
+                }
+            }
+        }
+
+        let annotations: Vec<String> = series
+            .iter()
+            .map(|line| {
+                if !method.annotations {
+                    return String::new();
+                }
+                let (min, max) = range(&line.values);
+                let precision = Rounded::shared_precision(&[min, max], 2);
+                let rounded = |value| Rounded { value, precision };
+                format!(" min={} max={}", rounded(min), rounded(max))
+            })
+            .collect();
+        let label_width = if method.labels {
+            series.iter().map(|line| line.label.len() + 1).max().unwrap_or(0)
+        } else {
+            0
+        };
+        let annotation_width = annotations.iter().map(String::len).max().unwrap_or(0);
+        let width = method
+            .width
+            .unwrap_or_else(|| Wrap::detect().width.saturating_sub(label_width + annotation_width).max(1));
+
+        let spark_width = series.iter().map(|line| line.values.len().min(width)).max().unwrap_or(0);
+
+        // Align the annotations of sparklines of different lengths
+        let lines: Vec<String> = series
+            .iter()
+            .zip(&annotations)
+            .map(|(line, annotation)| {
+                let spark = draw(&resample(&line.values, width, method.reduction));
+                let label = if method.labels {
+                    format!("{:>w$} ", line.label, w = label_width - 1)
+                } else {
+                    String::new()
+                };
+                let padding = if annotation.is_empty() {
+                    String::new()
+                } else {
+                    " ".repeat(spark_width - spark.chars().count())
+                };
+                format!("{label}{spark}{padding}{annotation}")
+            })
+            .collect();
+        write!(f, "{}", lines.join("\n"))
+    }
+}
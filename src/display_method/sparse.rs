@@ -0,0 +1,173 @@
+//! ### `Sparse` Display Method
+//!
+//! This module contains the `Sparse` display method for listing only the non-zero entries of arrays.
+
+use ndarray::{Data, Dimension};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+#[cfg(feature = "sprs")]
+use crate::DisplaySparse;
+use crate::{
+    DisplayArray, Numeric,
+    display_method::{DisplayMethod, block::Block, describe::tuple},
+};
+
+/// Order in which the entries of an array are listed.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum EntryOrder {
+    /// Entries are listed in row-major order of their indices.
+    Index,
+    /// Entries are listed from the largest magnitude to the smallest, ties in row-major order.
+    Magnitude,
+}
+
+/// Display method that lists the index and value of each entry of an array whose magnitude exceeds a tolerance.
+///
+/// The list is followed by the number of entries listed out of all the entries of the array, and their density.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct Sparse {
+    /// Magnitude which the value of an entry must exceed for it to be listed.
+    pub tolerance: f64,
+    /// Order in which the entries are listed.
+    pub order: EntryOrder,
+    /// Optional maximum number of entries listed, with the remainder counted on a final line.
+    pub limit: Option<usize>,
+    /// Number of decimal places shown in the density.
+    pub precision: usize,
+}
+
+impl Sparse {
+    /// Construct a new `Sparse` instance which lists every non-zero entry in index order.
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            tolerance: 0.0,
+            order: EntryOrder::Index,
+            limit: None,
+            precision: 2,
+        }
+    }
+
+    /// List only the entries whose magnitude exceeds the given tolerance.
+    #[must_use]
+    #[inline]
+    pub const fn with_tolerance(mut self, tolerance: f64) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Set the order in which the entries are listed.
+    #[must_use]
+    #[inline]
+    pub const fn with_order(mut self, order: EntryOrder) -> Self {
+        self.order = order;
+        self
+    }
+
+    /// List at most the given number of entries, counting the remainder on a final line.
+    #[must_use]
+    #[inline]
+    pub const fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Set the number of decimal places shown in the density.
+    #[must_use]
+    #[inline]
+    pub const fn with_precision(mut self, precision: usize) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    /// List the entries of one array, followed by their count and density.
+    fn render<'e, A, I>(&self, entries: I, total: usize) -> Vec<String>
+    where
+        A: Display + Numeric + 'e,
+        I: Iterator<Item = (Vec<usize>, &'e A)>,
+    {
+        let mut listed: Vec<(Vec<usize>, &A)> = entries
+            .filter(|entry| entry.1.to_f64().abs() > self.tolerance || entry.1.to_f64().is_nan())
+            .collect();
+        if self.order == EntryOrder::Magnitude {
+            listed.sort_by(|a, b| b.1.to_f64().abs().total_cmp(&a.1.to_f64().abs()));
+        }
+        let count = listed.len();
+        let shown = self.limit.map_or(count, |limit| limit.min(count));
+
+        let indices: Vec<String> = listed.iter().take(shown).map(|entry| tuple(&entry.0)).collect();
+        let values: Vec<String> = listed.iter().take(shown).map(|entry| entry.1.to_string()).collect();
+        let index_width = indices.iter().map(String::len).max().unwrap_or(0);
+        let value_width = values.iter().map(|value| value.chars().count()).max().unwrap_or(0);
+        let mut lines: Vec<String> = indices
+            .iter()
+            .zip(&values)
+            .map(|(index, value)| format!("{index:<index_width$} {value:>value_width$}"))
+            .collect();
+        if shown < count {
+            lines.push(format!("\u{2026} {} more", count - shown));
+        }
+        let density = if total == 0 {
+            0.0
+        } else {
+            100.0 * count.to_f64() / total.to_f64()
+        };
+        lines.push(format!("nnz: {count} of {total} (density {density:.*}%)", self.precision));
+        lines
+    }
+}
+
+impl Default for Sparse {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DisplayMethod for Sparse {}
+
+impl<S, D> Display for DisplayArray<'_, S, D, Sparse>
+where
+    S: Data,
+    S::Elem: Display + Numeric,
+    D: Dimension,
+{
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let method = self.settings();
+        let panels: Vec<Block> = self
+            .arrays
+            .iter()
+            .map(|arr| {
+                let view = arr.view().into_dyn();
+                let entries = view.indexed_iter().map(|entry| (entry.0.slice().to_vec(), entry.1));
+                Block::new(method.render(entries, arr.len()))
+            })
+            .collect();
+        write!(f, "{}", Block::beside(&panels, "  ").lines.join("\n"))
+    }
+}
+
+#[cfg(feature = "sprs")]
+impl<N, D> Display for DisplaySparse<'_, N, D, Sparse>
+where
+    N: Display + Numeric,
+    D: Dimension,
+{
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let method = self.settings();
+        let panels: Vec<Block> = self
+            .arrays
+            .iter()
+            .map(|arr| {
+                let entries = arr.entries.iter().map(|entry| (entry.0.slice().to_vec(), entry.1));
+                Block::new(method.render(entries, arr.shape.size()))
+            })
+            .collect();
+        write!(f, "{}", Block::beside(&panels, "  ").lines.join("\n"))
+    }
+}
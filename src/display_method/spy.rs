@@ -0,0 +1,158 @@
+//! ### `Spy` Display Method
+//!
+//! This module contains the `Spy` display method for drawing the pattern of non-zero entries of 2-D arrays.
+
+use ndarray::{Data, Ix2};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+#[cfg(feature = "sprs")]
+use crate::DisplaySparse;
+use crate::{
+    DisplayArray, Numeric,
+    display_method::{
+        DisplayMethod,
+        block::Block,
+        canvas::{Canvas, Scale},
+    },
+};
+
+/// Display method that draws each entry of 2-D arrays whose magnitude exceeds a tolerance as a Braille dot.
+///
+/// Arrays too large for the given size are downsampled, each dot then standing for a square block of entries which is
+/// drawn if any of them is non-zero. Each picture is followed by its number of non-zero entries and its bandwidth.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct Spy {
+    /// Largest number of characters across.
+    pub width: usize,
+    /// Largest number of lines down.
+    pub height: usize,
+    /// Magnitude which the value of an entry must exceed for it to be drawn.
+    pub tolerance: f64,
+}
+
+impl Spy {
+    /// Construct a new `Spy` instance which draws every non-zero entry, within 40 characters across and 20 lines down.
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            width: 40,
+            height: 20,
+            tolerance: 0.0,
+        }
+    }
+
+    /// Set the largest number of characters across and lines down, beyond which arrays are downsampled.
+    #[must_use]
+    #[inline]
+    pub const fn with_size(mut self, width: usize, height: usize) -> Self {
+        self.width = if width == 0 { 1 } else { width };
+        self.height = if height == 0 { 1 } else { height };
+        self
+    }
+
+    /// Draw only the entries whose magnitude exceeds the given tolerance.
+    #[must_use]
+    #[inline]
+    pub const fn with_tolerance(mut self, tolerance: f64) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Draw the non-zero entries of an array, framed by their indices, above their count and bandwidth.
+    ///
+    /// Entries are given as their row, column and value, and any entries of the shape not given are taken to be zero.
+    fn render<I>(&self, shape: (usize, usize), entries: I) -> Vec<String>
+    where
+        I: Iterator<Item = (usize, usize, f64)>,
+    {
+        let (rows, cols) = shape;
+        // Both axes share one step so that the pattern keeps its proportions, and the public size may be zero
+        let step = rows
+            .div_ceil(self.height.max(1) * 4)
+            .max(cols.div_ceil(self.width.max(1) * 2))
+            .max(1);
+        let mut canvas = Canvas::new(cols.div_ceil(step * 2), rows.div_ceil(step * 4), false);
+        let (mut count, mut lower, mut upper) = (0_usize, 0, 0);
+        for (row, col, value) in entries {
+            let magnitude = value.abs();
+            if magnitude > self.tolerance || magnitude.is_nan() {
+                canvas.set(col.div_euclid(step), row.div_euclid(step), 0);
+                count += 1;
+                lower = lower.max(row.saturating_sub(col));
+                upper = upper.max(col.saturating_sub(row));
+            }
+        }
+
+        let x = Scale {
+            min: 0.0,
+            max: (canvas.width.saturating_sub(1) * step * 2).to_f64(),
+            precision: 0,
+        };
+        let y = Scale {
+            min: (canvas.height.saturating_sub(1) * step * 4).to_f64(),
+            max: 0.0,
+            precision: 0,
+        };
+        let mut lines = Canvas::framed(&canvas.rows(), canvas.width, &x, &y);
+        let density = if rows * cols == 0 {
+            0.0
+        } else {
+            100.0 * count.to_f64() / (rows * cols).to_f64()
+        };
+        lines.push(format!("nnz: {count} of {rows}x{cols} ({density:.2}%)"));
+        lines.push(if step > 1 {
+            format!("bandwidth: lower {lower}, upper {upper}  dot: {step}x{step} entries")
+        } else {
+            format!("bandwidth: lower {lower}, upper {upper}")
+        });
+        lines
+    }
+}
+
+impl Default for Spy {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DisplayMethod for Spy {}
+
+impl<S> Display for DisplayArray<'_, S, Ix2, Spy>
+where
+    S: Data,
+    S::Elem: Numeric,
+{
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let method = self.settings();
+        let panels: Vec<Block> = self
+            .arrays
+            .iter()
+            .map(|arr| {
+                let entries = arr.indexed_iter().map(|entry| (entry.0.0, entry.0.1, entry.1.to_f64()));
+                Block::new(method.render(arr.dim(), entries))
+            })
+            .collect();
+        write!(f, "{}", Block::beside(&panels, "  ").lines.join("\n"))
+    }
+}
+
+#[cfg(feature = "sprs")]
+impl<N: Numeric> Display for DisplaySparse<'_, N, Ix2, Spy> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let method = self.settings();
+        let panels: Vec<Block> = self
+            .arrays
+            .iter()
+            .map(|arr| {
+                let entries = arr.entries.iter().map(|entry| (entry.0[0], entry.0[1], entry.1.to_f64()));
+                Block::new(method.render((arr.shape[0], arr.shape[1]), entries))
+            })
+            .collect();
+        write!(f, "{}", Block::beside(&panels, "  ").lines.join("\n"))
+    }
+}
@@ -0,0 +1,136 @@
+//! ### Summary statistics
+//!
+//! This module contains functions which summarise the values of a numeric array for display beneath it.
+
+use ndarray::{ArrayBase, Data, Dimension};
+
+use crate::{Numeric as _, display_method::describe::tuple, display_method::number::Rounded};
+
+/// Number of decimal places shown for statistics, which the extremes drop if both are integral.
+const PRECISION: usize = 4;
+
+/// Running statistics of the values of an array.
+#[derive(Default)]
+struct Summary {
+    /// Number of elements.
+    count: usize,
+    /// Number of finite values.
+    finite: usize,
+    /// Number of NaN values.
+    nan: usize,
+    /// Number of infinite values.
+    inf: usize,
+    /// Number of values equal to zero.
+    zeros: usize,
+    /// Smallest non-NaN value and its index.
+    min: Option<(f64, Vec<usize>)>,
+    /// Largest non-NaN value and its index.
+    max: Option<(f64, Vec<usize>)>,
+    /// Largest magnitude of the finite values, which the running mean and sum of squares are divided by.
+    scale: f64,
+    /// Running mean of the finite values, divided by the scale.
+    mean: f64,
+    /// Running sum of squared differences from the mean of the finite values, divided by the square of the scale.
+    m2: f64,
+}
+
+impl Summary {
+    /// Add a value found at the given index.
+    fn push(&mut self, value: f64, index: &[usize]) {
+        self.count += 1;
+        if value.is_nan() {
+            self.nan += 1;
+            return;
+        }
+        if value == 0.0 {
+            self.zeros += 1;
+        }
+        if self.min.as_ref().is_none_or(|&(min, _)| value < min) {
+            self.min = Some((value, index.to_vec()));
+        }
+        if self.max.as_ref().is_none_or(|&(max, _)| value > max) {
+            self.max = Some((value, index.to_vec()));
+        }
+        if value.is_infinite() {
+            self.inf += 1;
+            return;
+        }
+
+        // Welford's update keeps the variance accurate for large arrays, and dividing by the largest magnitude so far keeps
+        // the squared differences from overflowing for very large values
+        let magnitude = value.abs();
+        if magnitude > self.scale {
+            let ratio = self.scale / magnitude;
+            self.mean *= ratio;
+            self.m2 *= ratio * ratio;
+            self.scale = magnitude;
+        }
+        let scaled = if self.scale > 0.0 { value / self.scale } else { 0.0 };
+        self.finite += 1;
+        let delta = scaled - self.mean;
+        self.mean += delta / self.finite.to_f64();
+        self.m2 += delta * (scaled - self.mean);
+    }
+
+    /// Render a value and the index at which it was found, to the given number of decimal places.
+    fn extreme(name: &str, extreme: Option<&(f64, Vec<usize>)>, precision: usize) -> String {
+        extreme.map_or_else(
+            || format!("{name}=-"),
+            |found| {
+                format!(
+                    "{name}={} at {}",
+                    Rounded {
+                        value: found.0,
+                        precision
+                    },
+                    tuple(&found.1)
+                )
+            },
+        )
+    }
+
+    /// Render a statistic for display.
+    const fn rounded(value: f64) -> Rounded {
+        Rounded {
+            value,
+            precision: PRECISION,
+        }
+    }
+
+    /// Lines describing the statistics.
+    fn lines(&self) -> Vec<String> {
+        let (mean, std) = if self.finite == 0 {
+            ("-".to_owned(), "-".to_owned())
+        } else {
+            (
+                Self::rounded(self.mean * self.scale).to_string(),
+                Self::rounded((self.m2 / self.finite.to_f64()).sqrt() * self.scale).to_string(),
+            )
+        };
+        // The extremes share their decimal places, which are dropped if both are integral
+        let extremes = self.min.iter().chain(&self.max).map(|found| &found.0);
+        let precision = Rounded::shared_precision(extremes, PRECISION);
+        vec![
+            format!("count={} finite={} mean={mean} std={std}", self.count, self.finite),
+            format!(
+                "{} {}",
+                Self::extreme("min", self.min.as_ref(), precision),
+                Self::extreme("max", self.max.as_ref(), precision)
+            ),
+            format!("nan={} inf={} zeros={}", self.nan, self.inf, self.zeros),
+        ]
+    }
+}
+
+/// Summarise the values of an array as lines of count, mean, standard deviation, extremes and special values.
+///
+/// The count of all values is followed by the count of finite values, over which the mean and (population) standard
+/// deviation are taken. The extremes are taken over all but NaN values.
+#[inline]
+pub fn summarise<S: Data, D: Dimension>(arr: &ArrayBase<S, D>, numeric: fn(&S::Elem) -> f64) -> Vec<String> {
+    let mut summary = Summary::default();
+    for (index, elem) in arr.view().into_dyn().indexed_iter() {
+        summary.push(numeric(elem), index.slice());
+    }
+    summary.lines()
+}
@@ -0,0 +1,324 @@
+//! ### `Surface` Display Method
+//!
+//! This module contains the `Surface` display method for drawing 2-D height fields as wireframe surfaces.
+
+use ndarray::{Array2, Data, Ix2};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use crate::{
+    DisplayArray, Numeric,
+    display_method::{
+        DisplayMethod,
+        block::Block,
+        canvas::{Canvas, Scale},
+        number::Rounded,
+    },
+};
+
+/// Height of the highest point of a surface without exaggeration, as a fraction of the width of its base.
+const RELIEF: f64 = 0.3;
+
+/// Distance, in dots, by which a line may lie behind the nearest surface and still be drawn.
+const DEPTH_TOLERANCE: f64 = 2.0;
+
+/// How the surface is projected onto the screen.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Projection {
+    /// The surface is turned by the azimuth and tilted towards the viewer by the elevation.
+    Isometric,
+    /// The surface is turned by the azimuth and its depth recedes at the elevation angle, at half scale.
+    Oblique,
+}
+
+/// Display method that draws a 2-D array of heights as a wireframe surface on a Braille canvas.
+///
+/// Rows run from the back of the surface to the front and columns from left to right, and lines hidden behind the
+/// surface are removed. Side-by-side arrays share the same height scale.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct Surface {
+    /// Number of characters across the canvas.
+    pub width: usize,
+    /// Number of lines down the canvas.
+    pub height: usize,
+    /// How the surface is projected onto the screen.
+    pub projection: Projection,
+    /// Angle in degrees by which the surface is turned about its vertical axis.
+    pub azimuth: f64,
+    /// Angle in degrees from which the surface is viewed above its base.
+    pub elevation: f64,
+    /// Factor by which heights are stretched.
+    pub exaggeration: f64,
+    /// Greatest number of wireframe lines drawn in each direction.
+    pub lines: usize,
+    /// Number of decimal places shown in the height labels.
+    pub precision: usize,
+}
+
+impl Surface {
+    /// Construct a new `Surface` instance drawn isometrically on a canvas of the given number of characters across and
+    /// lines down.
+    #[must_use]
+    #[inline]
+    pub const fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            projection: Projection::Isometric,
+            azimuth: 45.0,
+            elevation: 30.0,
+            exaggeration: 1.0,
+            lines: 16,
+            precision: 2,
+        }
+    }
+
+    /// Set how the surface is projected onto the screen.
+    #[must_use]
+    #[inline]
+    pub const fn with_projection(mut self, projection: Projection) -> Self {
+        self.projection = projection;
+        self
+    }
+
+    /// Set the angles in degrees by which the surface is turned about its vertical axis and viewed above its base.
+    #[must_use]
+    #[inline]
+    pub const fn with_view(mut self, azimuth: f64, elevation: f64) -> Self {
+        self.azimuth = azimuth;
+        self.elevation = elevation;
+        self
+    }
+
+    /// Set the factor by which heights are stretched.
+    #[must_use]
+    #[inline]
+    pub const fn with_exaggeration(mut self, exaggeration: f64) -> Self {
+        self.exaggeration = exaggeration;
+        self
+    }
+
+    /// Set the greatest number of wireframe lines drawn in each direction.
+    #[must_use]
+    #[inline]
+    pub const fn with_lines(mut self, lines: usize) -> Self {
+        self.lines = if lines == 0 { 1 } else { lines };
+        self
+    }
+
+    /// Set the number of decimal places shown in the height labels.
+    #[must_use]
+    #[inline]
+    pub const fn with_precision(mut self, precision: usize) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    /// Project each finite point of a field onto the screen, as across, up and away from the viewer.
+    fn project(&self, field: &Array2<f64>, z: &Scale) -> Array2<Option<[f64; 3]>> {
+        let (rows, cols) = field.dim();
+        let extent = rows.max(cols).saturating_sub(1).max(1).to_f64();
+        let (sin_azimuth, cos_azimuth) = self.azimuth.to_radians().sin_cos();
+        let (sin_elevation, cos_elevation) = self.elevation.to_radians().sin_cos();
+        Array2::from_shape_fn((rows, cols), |(row, col)| {
+            let value = field[(row, col)];
+            value.is_finite().then(|| {
+                let across = 2.0 * col.to_f64() / extent - cols.saturating_sub(1).to_f64() / extent;
+                let away = rows.saturating_sub(1).to_f64() / extent - 2.0 * row.to_f64() / extent;
+                let up = 2.0 * RELIEF * self.exaggeration * z.fraction(value);
+                let turned_across = across.mul_add(cos_azimuth, -away * sin_azimuth);
+                let turned_away = across.mul_add(sin_azimuth, away * cos_azimuth);
+                match self.projection {
+                    Projection::Isometric => [
+                        turned_across,
+                        turned_away.mul_add(sin_elevation, up * cos_elevation),
+                        turned_away.mul_add(cos_elevation, -up * sin_elevation),
+                    ],
+                    Projection::Oblique => [
+                        (0.5 * turned_away).mul_add(cos_elevation, turned_across),
+                        (0.5 * turned_away).mul_add(sin_elevation, up),
+                        turned_away,
+                    ],
+                }
+            })
+        })
+    }
+
+    /// Draw the wireframe of a field, removing the lines hidden behind its surface.
+    fn render(&self, field: &Array2<f64>, z: &Scale) -> Vec<String> {
+        let mut canvas = Canvas::new(self.width, self.height, false);
+        let points = fit(&self.project(field, z), canvas.dot_width(), canvas.dot_height());
+        let (rows, cols) = points.dim();
+
+        let mut buffer = DepthBuffer::new(canvas.dot_width(), canvas.dot_height());
+        for row in 1..rows {
+            for col in 1..cols {
+                let corners = [
+                    points[(row - 1, col - 1)],
+                    points[(row - 1, col)],
+                    points[(row, col)],
+                    points[(row, col - 1)],
+                ];
+                if let [Some(a), Some(b), Some(c), Some(d)] = corners {
+                    buffer.fill([a, b, c]);
+                    buffer.fill([a, c, d]);
+                }
+            }
+        }
+
+        // Draw every few rows and columns, always including the edges of the surface
+        let stride = rows.max(cols).div_ceil(self.lines.max(1)).max(1);
+        let drawn = |index: usize, count: usize| index.is_multiple_of(stride) || index + 1 == count;
+        for ((row, col), point) in points.indexed_iter() {
+            let Some(from) = *point else { continue };
+            let neighbours = [
+                (drawn(row, rows) && col + 1 < cols).then(|| points[(row, col + 1)]),
+                (drawn(col, cols) && row + 1 < rows).then(|| points[(row + 1, col)]),
+            ];
+            for to in neighbours.into_iter().flatten().flatten() {
+                buffer.line(&mut canvas, from, to);
+            }
+        }
+        canvas.rows()
+    }
+}
+
+impl Default for Surface {
+    #[inline]
+    fn default() -> Self {
+        Self::new(60, 20)
+    }
+}
+
+impl DisplayMethod for Surface {}
+
+/// Point of a surface on the canvas, in dots from the top left, with its distance from the viewer in dots.
+#[derive(Debug, Clone, Copy)]
+struct Point {
+    /// Dots from the left edge.
+    x: f64,
+    /// Dots from the top edge.
+    y: f64,
+    /// Distance from the viewer.
+    depth: f64,
+}
+
+/// Scale and centre projected points to fill a canvas of the given number of dots across and down.
+fn fit(projected: &Array2<Option<[f64; 3]>>, dot_width: usize, dot_height: usize) -> Array2<Option<Point>> {
+    let across = Scale::fit(projected.iter().flatten().map(|point| point[0]), 0);
+    let up = Scale::fit(projected.iter().flatten().map(|point| point[1]), 0);
+    let (span_across, span_up) = (across.max - across.min, up.max - up.min);
+    let dots = (dot_width.saturating_sub(1).to_f64() / span_across).min(dot_height.saturating_sub(1).to_f64() / span_up);
+    let margin_x = span_across.mul_add(-dots, dot_width.saturating_sub(1).to_f64()) / 2.0;
+    let margin_y = span_up.mul_add(-dots, dot_height.saturating_sub(1).to_f64()) / 2.0;
+    projected.map(|point| {
+        point.map(|[x, y, depth]| Point {
+            x: (x - across.min).mul_add(dots, margin_x),
+            y: (up.max - y).mul_add(dots, margin_y),
+            depth: depth * dots,
+        })
+    })
+}
+
+/// Distance from the viewer of the nearest part of the surface behind each dot of a canvas.
+struct DepthBuffer {
+    /// Number of dots across.
+    width: usize,
+    /// Number of dots down.
+    height: usize,
+    /// Nearest distance behind each dot, row by row.
+    depths: Vec<f64>,
+}
+
+impl DepthBuffer {
+    /// Construct an empty buffer of the given number of dots across and down.
+    fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            depths: vec![f64::INFINITY; width * height],
+        }
+    }
+
+    /// Record the distances of the dots covered by a triangle, where it is nearer than anything recorded already.
+    #[expect(
+        clippy::as_conversions,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        reason = "The bounds are clamped to the buffer."
+    )]
+    fn fill(&mut self, triangle: [Point; 3]) {
+        let [a, b, c] = triangle;
+        let area = (b.x - a.x).mul_add(c.y - a.y, -(c.x - a.x) * (b.y - a.y));
+        if area.abs() < f64::EPSILON {
+            return;
+        }
+        let bound = |values: [f64; 3], limit: usize| {
+            let low = values.iter().copied().fold(f64::INFINITY, f64::min).floor();
+            let high = values.iter().copied().fold(f64::NEG_INFINITY, f64::max).ceil();
+            let last = limit.saturating_sub(1).to_f64();
+            (low.clamp(0.0, last) as usize, high.clamp(0.0, last) as usize)
+        };
+        let (left, right) = bound([a.x, b.x, c.x], self.width);
+        let (top, bottom) = bound([a.y, b.y, c.y], self.height);
+        for y in top..=bottom {
+            for x in left..=right {
+                let (px, py) = (x.to_f64(), y.to_f64());
+                let weight_a = (b.x - px).mul_add(c.y - py, -(c.x - px) * (b.y - py)) / area;
+                let weight_b = (c.x - px).mul_add(a.y - py, -(a.x - px) * (c.y - py)) / area;
+                let weight_c = 1.0 - weight_a - weight_b;
+                if weight_a >= 0.0 && weight_b >= 0.0 && weight_c >= 0.0 {
+                    let depth = weight_c.mul_add(c.depth, weight_a.mul_add(a.depth, weight_b * b.depth));
+                    let cell = y * self.width + x;
+                    self.depths[cell] = self.depths[cell].min(depth);
+                }
+            }
+        }
+    }
+
+    /// Draw the dots of a straight line which are not hidden behind the surface.
+    #[expect(
+        clippy::as_conversions,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        reason = "Points lie within the canvas."
+    )]
+    fn line(&self, canvas: &mut Canvas, from: Point, to: Point) {
+        let steps = (to.x - from.x).abs().max((to.y - from.y).abs()).ceil().max(1.0);
+        for step in 0..=steps as usize {
+            let fraction = step.to_f64() / steps;
+            let (x, y) = (
+                (to.x - from.x).mul_add(fraction, from.x).round().max(0.0) as usize,
+                (to.y - from.y).mul_add(fraction, from.y).round().max(0.0) as usize,
+            );
+            let depth = (to.depth - from.depth).mul_add(fraction, from.depth);
+            let nearest = self.depths.get(y * self.width + x).copied().unwrap_or(f64::INFINITY);
+            if x < self.width && depth <= nearest + DEPTH_TOLERANCE {
+                canvas.set(x, y, 0);
+            }
+        }
+    }
+}
+
+impl<S> Display for DisplayArray<'_, S, Ix2, Surface>
+where
+    S: Data,
+    S::Elem: Numeric,
+{
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let method = &self.settings();
+        let fields: Vec<Array2<f64>> = self.arrays.iter().map(|arr| arr.map(Numeric::to_f64)).collect();
+        let z = Scale::fit(fields.iter().flatten().copied(), method.precision);
+
+        let panels: Vec<Block> = fields.iter().map(|field| Block::new(method.render(field, &z))).collect();
+        let mut lines = Block::beside(&panels, "  ").lines;
+        let [low, high] = [z.min, z.max].map(|value| Rounded {
+            value,
+            precision: method.precision,
+        });
+        lines.push(format!("height: {low} to {high}"));
+        write!(f, "{}", lines.join("\n"))
+    }
+}
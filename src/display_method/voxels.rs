@@ -0,0 +1,226 @@
+//! ### `Voxels` Display Method
+//!
+//! This module contains the `Voxels` display method for drawing 3-D boolean arrays as isometric blocks.
+
+use ndarray::{ArrayBase, Data, Ix3};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use crate::{
+    DisplayArray,
+    display_method::{DisplayMethod, block::Block, canvas::RESET},
+};
+
+/// Characters drawn for the top, left and right faces of each voxel when faces are shaded by glyph.
+const GLYPHS: [char; 3] = ['\u{2588}', '\u{2593}', '\u{2592}'];
+
+/// Colours of the top, left and right faces of each voxel when faces are shaded by colour, as ANSI escape sequences.
+const SHADES: [&str; 3] = ["\x1b[97m", "\x1b[37m", "\x1b[90m"];
+
+/// Character drawn for every face of each voxel when faces are shaded by colour.
+const SOLID: char = '\u{2588}';
+
+/// Offset of each character of a voxel from its position on the screen, as the row, column and face drawn there.
+///
+/// The top face spans the first line and the left and right faces share the two lines beneath.
+const SPRITE: [(usize, usize, usize); 12] = [
+    (0, 0, 0),
+    (0, 1, 0),
+    (0, 2, 0),
+    (0, 3, 0),
+    (1, 0, 1),
+    (1, 1, 1),
+    (1, 2, 2),
+    (1, 3, 2),
+    (2, 0, 1),
+    (2, 1, 1),
+    (2, 2, 2),
+    (2, 3, 2),
+];
+
+/// How the faces of each voxel are told apart.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Shading {
+    /// Faces are drawn with full, dark and medium shade characters.
+    Glyphs,
+    /// Faces are drawn with full blocks in bright, light and dark grey, using ANSI escape sequences.
+    Colour,
+}
+
+/// Display method that draws the `true` elements of 3-D boolean arrays as blocks in an isometric picture.
+///
+/// The slices along the upward axis are stacked from the bottom up, with the first row of each slice at the back and the
+/// first column on the left.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Voxels {
+    /// Axis of the array which points up.
+    pub up: usize,
+    /// Number of quarter turns anticlockwise about the upward axis, seen from above.
+    pub turns: usize,
+    /// How the faces of each voxel are told apart.
+    pub shading: Shading,
+}
+
+impl Voxels {
+    /// Construct a new `Voxels` instance with the first axis pointing up, shaded by glyph.
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            up: 0,
+            turns: 0,
+            shading: Shading::Glyphs,
+        }
+    }
+
+    /// Set the axis of the array which points up.
+    #[must_use]
+    #[inline]
+    pub const fn with_up(mut self, up: usize) -> Self {
+        self.up = up;
+        self
+    }
+
+    /// Set the number of quarter turns anticlockwise about the upward axis, seen from above.
+    #[must_use]
+    #[inline]
+    pub const fn with_turns(mut self, turns: usize) -> Self {
+        self.turns = turns.rem_euclid(4);
+        self
+    }
+
+    /// Set how the faces of each voxel are told apart.
+    #[must_use]
+    #[inline]
+    pub const fn with_shading(mut self, shading: Shading) -> Self {
+        self.shading = shading;
+        self
+    }
+
+    /// Positions across, towards the viewer and up of the filled voxels of an array, from the back to the front.
+    fn positions<S>(&self, arr: &ArrayBase<S, Ix3>) -> Vec<[usize; 3]>
+    where
+        S: Data<Elem = bool>,
+    {
+        let (rows, cols) = match self.up {
+            0 => (1, 2),
+            1 => (0, 2),
+            _ => (0, 1),
+        };
+        let shape = arr.shape();
+        let (last_row, last_col) = (shape[rows].saturating_sub(1), shape[cols].saturating_sub(1));
+        let mut positions: Vec<[usize; 3]> = arr
+            .indexed_iter()
+            .filter(|voxel| *voxel.1)
+            .map(|voxel| {
+                let index = [voxel.0.0, voxel.0.1, voxel.0.2];
+                let (row, col) = (index[rows], index[cols]);
+                let (across, towards) = match self.turns {
+                    1 => (row, last_col - col),
+                    2 => (last_col - col, last_row - row),
+                    3 => (last_row - row, col),
+                    _ => (col, row),
+                };
+                [across, towards, index[self.up.min(2)]]
+            })
+            .collect();
+        positions.sort_by_key(|position| position[0] + position[1] + position[2]);
+        positions
+    }
+
+    /// Draw the filled voxels of an array, nearer voxels hiding those behind them.
+    fn render<S>(&self, arr: &ArrayBase<S, Ix3>) -> Vec<String>
+    where
+        S: Data<Elem = bool>,
+    {
+        let positions = self.positions(arr);
+        let (Some(depth), Some(height)) = (
+            positions.iter().map(|position| position[1]).max(),
+            positions.iter().map(|position| position[2]).max(),
+        ) else {
+            return Vec::new();
+        };
+        let width = positions.iter().map(|position| position[0]).max().unwrap_or(0);
+
+        // Each step across moves right and down, each step towards the viewer moves left and down
+        let mut faces = vec![vec![None; 2 * (width + depth) + 4]; width + depth + 2 * height + 3];
+        for &[across, towards, up] in &positions {
+            let (row, col) = (across + towards + 2 * (height - up), 2 * (across + depth - towards));
+            for (down, right, face) in SPRITE {
+                faces[row + down][col + right] = Some(face);
+            }
+        }
+
+        // Crop the picture to the faces drawn
+        faces.retain(|line| line.iter().any(Option::is_some));
+        let indent = faces
+            .iter()
+            .filter_map(|line| line.iter().position(Option::is_some))
+            .min()
+            .unwrap_or(0);
+        faces
+            .iter()
+            .map(|line| {
+                let mut text = String::new();
+                let mut current = None;
+                let end = line.iter().rposition(Option::is_some).map_or(0, |last| last + 1);
+                for &face in &line[indent..end] {
+                    match (face, self.shading) {
+                        (None, _) => text.push(' '),
+                        (Some(index), Shading::Glyphs) => text.push(GLYPHS[index]),
+                        (Some(index), Shading::Colour) => {
+                            if current != Some(index) {
+                                text.push_str(SHADES[index]);
+                                current = Some(index);
+                            }
+                            text.push(SOLID);
+                        }
+                    }
+                }
+                if current.is_some() {
+                    text.push_str(RESET);
+                }
+                text
+            })
+            .collect()
+    }
+}
+
+impl Default for Voxels {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DisplayMethod for Voxels {}
+
+impl<S> Display for DisplayArray<'_, S, Ix3, Voxels>
+where
+    S: Data<Elem = bool>,
+{
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let method = self.settings();
+        if method.up > 2 {
+            return write!(f, "Error: The upward axis must be 0, 1 or 2, but {} was given", method.up);
+        }
+        let pictures: Vec<Vec<String>> = self.arrays.iter().map(|arr| method.render(arr)).collect();
+        let height = pictures.iter().map(Vec::len).max().unwrap_or(0);
+
+        // Stand every picture on the same line, above its count of filled voxels
+        let panels: Vec<Block> = pictures
+            .into_iter()
+            .zip(&self.arrays)
+            .map(|(picture, arr)| {
+                let mut lines = vec![String::new(); height - picture.len()];
+                lines.extend(picture);
+                let filled = arr.iter().filter(|&&voxel| voxel).count();
+                lines.push(format!("voxels: {filled} of {} filled", arr.len()));
+                Block::new(lines)
+            })
+            .collect();
+        write!(f, "{}", Block::beside(&panels, "  ").lines.join("\n"))
+    }
+}
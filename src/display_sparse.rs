@@ -0,0 +1,345 @@
+//! ## `DisplaySparse`
+//!
+//! This module contains the `DisplaySparse` struct, a wrapper type used to format `sprs` sparse matrices and vectors
+//! without converting them to dense arrays.
+
+use ndarray::{Array, Dimension, Ix1, Ix2, OwnedRepr};
+use sprs::{CsMatBase, CsVecBase, SpIndex};
+use std::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    ops::{Deref, Range},
+};
+
+use crate::{CommaSeparated, DisplayArray, DoubleJoined, Joined, Rulers, Separated, Window, display_method::DisplayMethod};
+
+/// Number of indices displayed along each axis by element-wise methods when no window is given.
+const DEFAULT_EXTENT: usize = 20;
+
+/// Element of the displayed region of a sparse array, either a stored value or an implicit zero.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Entry<'a, N> {
+    /// Value stored in the sparse array.
+    Stored(&'a N),
+    /// Zero which is not stored, drawn as the given character.
+    Implicit(char),
+}
+
+impl<N> Clone for Entry<'_, N> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<N> Copy for Entry<'_, N> {}
+
+impl<N: Display> Display for Entry<'_, N> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match *self {
+            Self::Stored(value) => Display::fmt(value, f),
+            Self::Implicit(marker) => write!(f, "{marker}"),
+        }
+    }
+}
+
+/// Shape and stored entries of a sparse matrix or vector, borrowed for display.
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct SparseEntries<'a, N, D> {
+    /// Shape of the sparse array.
+    pub shape: D,
+    /// Index and value of each stored entry, in row-major order of the indices.
+    pub entries: Vec<(D, &'a N)>,
+}
+
+impl<'a, N, D: Dimension> SparseEntries<'a, N, D> {
+    /// Construct a new `SparseEntries` instance from the shape and stored entries of a sparse array, in any order.
+    #[must_use]
+    #[inline]
+    pub fn new(shape: D, mut entries: Vec<(D, &'a N)>) -> Self {
+        entries.sort_by(|a, b| a.0.slice().cmp(b.0.slice()));
+        Self { shape, entries }
+    }
+
+    /// Dense array of the given region, holding the stored entries within it and implicit zeros elsewhere.
+    fn region(&self, ranges: &[Range<usize>], implicit: char) -> Array<Entry<'a, N>, D> {
+        let mut shape = self.shape.clone();
+        for (len, range) in shape.slice_mut().iter_mut().zip(ranges) {
+            *len = range.len();
+        }
+        let mut region = Array::from_elem(shape, Entry::Implicit(implicit));
+        for entry in &self.entries {
+            if entry.0.slice().iter().zip(ranges).all(|(index, range)| range.contains(index)) {
+                let mut local = entry.0.clone();
+                for (index, range) in local.slice_mut().iter_mut().zip(ranges) {
+                    *index -= range.start;
+                }
+                region[local] = Entry::Stored(entry.1);
+            }
+        }
+        region
+    }
+}
+
+impl<'a, N, I, Iptr, IptrStorage, IndStorage, DataStorage> From<&'a CsMatBase<N, I, IptrStorage, IndStorage, DataStorage, Iptr>>
+    for SparseEntries<'a, N, Ix2>
+where
+    I: SpIndex,
+    Iptr: SpIndex,
+    IptrStorage: Deref<Target = [Iptr]>,
+    IndStorage: Deref<Target = [I]>,
+    DataStorage: Deref<Target = [N]>,
+{
+    #[inline]
+    fn from(matrix: &'a CsMatBase<N, I, IptrStorage, IndStorage, DataStorage, Iptr>) -> Self {
+        let entries = matrix
+            .iter()
+            .map(|entry| (Ix2(entry.1.0.index(), entry.1.1.index()), entry.0))
+            .collect();
+        Self::new(Ix2(matrix.rows(), matrix.cols()), entries)
+    }
+}
+
+impl<'a, N, I, IStorage, DStorage> From<&'a CsVecBase<IStorage, DStorage, N, I>> for SparseEntries<'a, N, Ix1>
+where
+    I: SpIndex,
+    IStorage: Deref<Target = [I]>,
+    DStorage: Deref<Target = [N]>,
+{
+    #[inline]
+    fn from(vector: &'a CsVecBase<IStorage, DStorage, N, I>) -> Self {
+        let entries = vector.iter().map(|entry| (Ix1(entry.0), entry.1)).collect();
+        Self::new(Ix1(vector.dim()), entries)
+    }
+}
+
+/// Display wrapper holding sparse arrays, the display method and its options.
+///
+/// Element-wise methods draw only the displayed region, with implicit zeros drawn as a marker. Without a window, the
+/// region holds the first 20 indices along each axis. The `Sparse` and `Spy` methods work from the stored entries alone.
+#[non_exhaustive]
+pub struct DisplaySparse<'a, N, D, M: DisplayMethod> {
+    /// The sparse arrays to be displayed.
+    pub arrays: Vec<SparseEntries<'a, N, D>>,
+    /// Character drawn in place of each implicit zero.
+    pub implicit: char,
+    /// Index labels drawn around each displayed block.
+    pub rulers: Option<Rulers>,
+    /// Region of the arrays to display.
+    pub window: Option<Window>,
+    /// Settings of the display method, if given rather than left at their defaults.
+    pub method: Option<M>,
+}
+
+impl<'a, N, D: Dimension, M: DisplayMethod> DisplaySparse<'a, N, D, M> {
+    /// Construct a new `DisplaySparse` instance referring to the given sparse arrays.
+    #[must_use]
+    #[inline]
+    pub const fn new(arrays: Vec<SparseEntries<'a, N, D>>) -> Self {
+        DisplaySparse {
+            arrays,
+            implicit: '.',
+            rulers: None,
+            window: None,
+            method: None,
+        }
+    }
+
+    /// Display the arrays with the given settings of the display method, rather than its defaults.
+    #[must_use]
+    #[inline]
+    pub fn with_method(mut self, method: M) -> Self {
+        self.method = Some(method);
+        self
+    }
+
+    /// Set the character drawn in place of each implicit zero, such as a space to leave them blank.
+    #[must_use]
+    #[inline]
+    pub const fn with_implicit(mut self, implicit: char) -> Self {
+        self.implicit = implicit;
+        self
+    }
+
+    /// Label the displayed rows and columns with their original indices.
+    #[must_use]
+    #[inline]
+    pub const fn with_rulers(mut self, rulers: Rulers) -> Self {
+        self.rulers = Some(rulers);
+        self
+    }
+
+    /// Display only the given region of the arrays, which is all that element-wise methods convert to dense form.
+    ///
+    /// Rulers are shown by default, and markers indicate where the arrays continue past the edges of the region. Without
+    /// a window, element-wise methods display the first 20 indices along each axis in the same way.
+    #[must_use]
+    #[inline]
+    pub fn with_window(mut self, window: Window) -> Self {
+        self.window = Some(window);
+        self
+    }
+
+    /// Display the region of the arrays with an element-wise method, through the dense display of the region alone.
+    fn fmt_region(&self, f: &mut Formatter<'_>) -> FmtResult
+    where
+        M: Clone,
+        for<'r> DisplayArray<'r, OwnedRepr<Entry<'a, N>>, D, M>: Display,
+    {
+        let Some(first) = self.arrays.first() else {
+            return Ok(());
+        };
+        let shape = first.shape.slice();
+        for (i, arr) in self.arrays.iter().enumerate().skip(1) {
+            if arr.shape.slice() != shape {
+                return write!(
+                    f,
+                    "Error: Arrays have different shapes. Array 0 shape: {shape:?}, Array {i} shape: {:?}",
+                    arr.shape.slice()
+                );
+            }
+        }
+
+        // Bound the region even without a window, so that large arrays are never made dense as a whole
+        let ranges: Vec<Range<usize>> = shape
+            .iter()
+            .enumerate()
+            .map(|(axis, &len)| {
+                self.window
+                    .as_ref()
+                    .map_or_else(|| 0..len.min(DEFAULT_EXTENT), |window| window.range(axis, len))
+            })
+            .collect();
+        let regions: Vec<Array<Entry<'a, N>, D>> = self.arrays.iter().map(|arr| arr.region(&ranges, self.implicit)).collect();
+        let mut display = DisplayArray::new(regions.iter().collect());
+        display.method.clone_from(&self.method);
+        display.rulers = self.rulers;
+        if self.window.is_some() || ranges.iter().zip(shape).any(|(range, &len)| range.len() < len) {
+            display.region = Some((ranges.iter().map(|range| range.start).collect(), shape.to_vec()));
+        }
+        display.fmt(f)
+    }
+}
+
+/// Implement `Display` for `DisplaySparse` with element-wise methods, which draw the displayed region.
+macro_rules! impl_display_region {
+    ($($method:ty),*) => {
+        $(
+            impl<N: Display, D: Dimension> Display for DisplaySparse<'_, N, D, $method> {
+                #[inline]
+                fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+                    self.fmt_region(f)
+                }
+            }
+        )*
+    };
+}
+
+impl_display_region!(CommaSeparated, DoubleJoined, Joined, Separated);
+
+/// Common trait for displayable `sprs` sparse arrays, the counterpart of `DisplayExt` for `ndarray` arrays.
+///
+/// `N` is the element type of the arrays, such as `f64`, and `D` is their dimension: `Ix2` for matrices and `Ix1` for
+/// vectors.
+pub trait DisplaySparseExt<N, D: Dimension> {
+    /// Construct a `DisplaySparse` type for the given data.
+    fn display<M: DisplayMethod>(&self) -> DisplaySparse<'_, N, D, M>;
+
+    /// Construct a `DisplaySparse` type for the given data, using the given settings of the display method.
+    #[inline]
+    fn display_with<M: DisplayMethod>(&self, method: M) -> DisplaySparse<'_, N, D, M> {
+        self.display().with_method(method)
+    }
+}
+
+impl<N, I, Iptr, IptrStorage, IndStorage, DataStorage> DisplaySparseExt<N, Ix2>
+    for CsMatBase<N, I, IptrStorage, IndStorage, DataStorage, Iptr>
+where
+    I: SpIndex,
+    Iptr: SpIndex,
+    IptrStorage: Deref<Target = [Iptr]>,
+    IndStorage: Deref<Target = [I]>,
+    DataStorage: Deref<Target = [N]>,
+{
+    #[inline]
+    fn display<M: DisplayMethod>(&self) -> DisplaySparse<'_, N, Ix2, M> {
+        DisplaySparse::new(vec![self.into()])
+    }
+}
+
+impl<N, I, Iptr, IptrStorage, IndStorage, DataStorage, const K: usize> DisplaySparseExt<N, Ix2>
+    for [&CsMatBase<N, I, IptrStorage, IndStorage, DataStorage, Iptr>; K]
+where
+    I: SpIndex,
+    Iptr: SpIndex,
+    IptrStorage: Deref<Target = [Iptr]>,
+    IndStorage: Deref<Target = [I]>,
+    DataStorage: Deref<Target = [N]>,
+{
+    #[inline]
+    fn display<M: DisplayMethod>(&self) -> DisplaySparse<'_, N, Ix2, M> {
+        DisplaySparse::new(self.iter().map(|&matrix| matrix.into()).collect())
+    }
+}
+
+impl<N, I, Iptr, IptrStorage, IndStorage, DataStorage> DisplaySparseExt<N, Ix2>
+    for &[&CsMatBase<N, I, IptrStorage, IndStorage, DataStorage, Iptr>]
+where
+    I: SpIndex,
+    Iptr: SpIndex,
+    IptrStorage: Deref<Target = [Iptr]>,
+    IndStorage: Deref<Target = [I]>,
+    DataStorage: Deref<Target = [N]>,
+{
+    #[inline]
+    fn display<M: DisplayMethod>(&self) -> DisplaySparse<'_, N, Ix2, M> {
+        DisplaySparse::new(self.iter().map(|&matrix| matrix.into()).collect())
+    }
+}
+
+impl<N, I, IStorage, DStorage> DisplaySparseExt<N, Ix1> for CsVecBase<IStorage, DStorage, N, I>
+where
+    I: SpIndex,
+    IStorage: Deref<Target = [I]>,
+    DStorage: Deref<Target = [N]>,
+{
+    #[inline]
+    fn display<M: DisplayMethod>(&self) -> DisplaySparse<'_, N, Ix1, M> {
+        DisplaySparse::new(vec![self.into()])
+    }
+}
+
+impl<N, I, IStorage, DStorage, const K: usize> DisplaySparseExt<N, Ix1> for [&CsVecBase<IStorage, DStorage, N, I>; K]
+where
+    I: SpIndex,
+    IStorage: Deref<Target = [I]>,
+    DStorage: Deref<Target = [N]>,
+{
+    #[inline]
+    fn display<M: DisplayMethod>(&self) -> DisplaySparse<'_, N, Ix1, M> {
+        DisplaySparse::new(self.iter().map(|&vector| vector.into()).collect())
+    }
+}
+
+impl<N, I, IStorage, DStorage> DisplaySparseExt<N, Ix1> for &[&CsVecBase<IStorage, DStorage, N, I>]
+where
+    I: SpIndex,
+    IStorage: Deref<Target = [I]>,
+    DStorage: Deref<Target = [N]>,
+{
+    #[inline]
+    fn display<M: DisplayMethod>(&self) -> DisplaySparse<'_, N, Ix1, M> {
+        DisplaySparse::new(self.iter().map(|&vector| vector.into()).collect())
+    }
+}
+
+impl<N, D, M: DisplayMethod + Clone + Default> DisplaySparse<'_, N, D, M> {
+    /// Settings of the display method: those given with `with_method`, or else its defaults.
+    #[must_use]
+    #[inline]
+    pub fn settings(&self) -> M {
+        self.method.clone().unwrap_or_default()
+    }
+}
@@ -119,7 +119,22 @@
 mod display_array;
 mod display_ext;
 mod display_method;
+#[cfg(feature = "sprs")]
+mod display_sparse;
+mod numeric;
+mod options;
 
 pub use display_array::DisplayArray;
 pub use display_ext::DisplayExt;
-pub use display_method::{CommaSeparated, DoubleJoined, Joined, Separated};
+pub use display_method::{
+    Arrows, BoxPlot, CommaSeparated, Confusion, Contour, DoubleJoined, EntryOrder, Groups, Histogram, Joined, LineChart,
+    Magnitude, Normalisation, Orientation, PointEncoding, Projection, Scatter, Separated, Shading, Sparkline, Sparse, Spy,
+    Surface, Voxels,
+};
+#[cfg(feature = "sprs")]
+pub use display_sparse::{DisplaySparse, DisplaySparseExt, Entry, SparseEntries};
+pub use numeric::Numeric;
+pub use options::{
+    AxisMap, BarPlacement, DataBars, Marginals, Mosaic, OrthoSlices, Overview, Reduction, Rulers, SliceHeaders, SliceStyle,
+    Window, Wrap,
+};
@@ -0,0 +1,63 @@
+//! ## `Numeric`
+//!
+//! This module provides the `Numeric` trait, which converts array elements to floating point values for numerical options.
+
+/// Elements which can be converted to a floating point value.
+pub trait Numeric {
+    /// Convert the element to a floating point value.
+    fn to_f64(&self) -> f64;
+
+    /// Convert the element to an exact count, if it is a whole number of zero or more.
+    #[expect(
+        clippy::as_conversions,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        reason = "The value is checked to be a whole number within the range of the count."
+    )]
+    #[inline]
+    fn to_count(&self) -> Option<u128> {
+        let value = self.to_f64();
+        (value >= 0.0 && value.fract() == 0.0 && value < u128::MAX.to_f64()).then_some(value as u128)
+    }
+}
+
+/// Implement `Numeric` for types which convert to `f64` without loss.
+macro_rules! impl_numeric_lossless {
+    ($($t:ty),*) => {
+        $(
+            impl Numeric for $t {
+                #[inline]
+                fn to_f64(&self) -> f64 {
+                    f64::from(*self)
+                }
+            }
+        )*
+    };
+}
+
+/// Implement `Numeric` for types which may lose precision when converted to `f64`.
+macro_rules! impl_numeric_lossy {
+    ($($t:ty),*) => {
+        $(
+            impl Numeric for $t {
+                #[expect(
+                    clippy::as_conversions,
+                    clippy::cast_precision_loss,
+                    reason = "Very large integers are only displayed approximately."
+                )]
+                #[inline]
+                fn to_f64(&self) -> f64 {
+                    *self as f64
+                }
+
+                #[inline]
+                fn to_count(&self) -> Option<u128> {
+                    u128::try_from(*self).ok()
+                }
+            }
+        )*
+    };
+}
+
+impl_numeric_lossless!(bool, f32, f64, i8, i16, i32, u8, u16, u32);
+impl_numeric_lossy!(i64, i128, isize, u64, u128, usize);
@@ -0,0 +1,92 @@
+//! ### `AxisMap`
+//!
+//! This module contains the `AxisMap` option, which chooses the axes used for the rows, columns and pages of a display.
+
+use std::iter::repeat_n;
+
+/// Assignment of array axes to the rows, columns and pages of the display.
+///
+/// By default the last axis forms the columns, the second-to-last the rows, and any remaining axes the pages.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct AxisMap {
+    /// Axis displayed down the rows of each block.
+    pub rows: usize,
+    /// Axis displayed across the columns of each block.
+    pub columns: usize,
+    /// Order in which the remaining axes are paged through, outermost first.
+    /// When empty, the remaining axes are paged through in ascending order.
+    pub pages: Vec<usize>,
+    /// Number of consecutive page axes in each group, outermost first.
+    /// Page axes beyond the listed groups each form a group of their own.
+    pub groups: Vec<usize>,
+}
+
+impl AxisMap {
+    /// Construct a new `AxisMap` instance with the given row and column axes.
+    #[must_use]
+    #[inline]
+    pub const fn new(rows: usize, columns: usize) -> Self {
+        Self {
+            rows,
+            columns,
+            pages: Vec::new(),
+            groups: Vec::new(),
+        }
+    }
+
+    /// Set the order in which the remaining axes are paged through, outermost first.
+    #[must_use]
+    #[inline]
+    pub fn with_pages(mut self, pages: Vec<usize>) -> Self {
+        self.pages = pages;
+        self
+    }
+
+    /// Page through the given groups of axes, outermost first, in order within each group.
+    ///
+    /// The axes of a group are paged through as a single flattened axis: consecutive pages are separated by one blank
+    /// line for each group whose position changes, rather than one for each axis.
+    #[must_use]
+    #[inline]
+    pub fn with_groups(mut self, groups: Vec<Vec<usize>>) -> Self {
+        self.groups = groups.iter().map(Vec::len).collect();
+        self.pages = groups.into_iter().flatten().collect();
+        self
+    }
+
+    /// Group of each of the given number of page axes, in paging order.
+    #[must_use]
+    #[inline]
+    pub fn page_groups(&self, count: usize) -> Vec<usize> {
+        let grouped = self.groups.iter().enumerate().flat_map(|(group, &len)| repeat_n(group, len));
+        grouped.chain(self.groups.len()..).take(count).collect()
+    }
+
+    /// Permutation which moves the page axes to the front, followed by the row and column axes.
+    ///
+    /// Returns `None` if the mapping does not describe each axis of an array with `ndim` dimensions exactly once.
+    #[must_use]
+    #[inline]
+    pub fn permutation(&self, ndim: usize) -> Option<Vec<usize>> {
+        let pages: Vec<usize> = if self.pages.is_empty() {
+            (0..ndim).filter(|&axis| axis != self.rows && axis != self.columns).collect()
+        } else {
+            self.pages.clone()
+        };
+
+        let mut order = pages;
+        order.push(self.rows);
+        order.push(self.columns);
+
+        let mut seen = vec![false; ndim];
+        for &axis in &order {
+            if axis >= ndim || seen[axis] {
+                return None;
+            }
+            seen[axis] = true;
+        }
+
+        (order.len() == ndim).then_some(order)
+    }
+}
@@ -0,0 +1,50 @@
+//! ### `DataBars`
+//!
+//! This module contains the `DataBars` option, which draws a bar proportional to the value of each element.
+
+/// Where each bar is drawn relative to its element.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum BarPlacement {
+    /// To the right of the element, on the same line.
+    Beside,
+    /// On a line of its own below each row, under the element.
+    Beneath,
+}
+
+/// Spreadsheet-style bars drawn with eighth-block glyphs, with a zero line separating negative and positive values.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct DataBars {
+    /// Number of characters spanned by the full range of values.
+    pub width: usize,
+    /// Where each bar is drawn relative to its element.
+    pub placement: BarPlacement,
+}
+
+impl DataBars {
+    /// Construct a new `DataBars` instance which draws bars of up to the given number of characters beside each element.
+    #[must_use]
+    #[inline]
+    pub const fn new(width: usize) -> Self {
+        Self {
+            width: if width == 0 { 1 } else { width },
+            placement: BarPlacement::Beside,
+        }
+    }
+
+    /// Set where each bar is drawn relative to its element.
+    #[must_use]
+    #[inline]
+    pub const fn with_placement(mut self, placement: BarPlacement) -> Self {
+        self.placement = placement;
+        self
+    }
+}
+
+impl Default for DataBars {
+    #[inline]
+    fn default() -> Self {
+        Self::new(8)
+    }
+}
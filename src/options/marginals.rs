@@ -0,0 +1,39 @@
+//! ### `Marginals`
+//!
+//! This module contains the `Marginals` option, which appends per-row and per-column reductions to each 2-D block.
+
+use crate::Reduction;
+
+/// Extra column of row reductions and extra row of column reductions, set apart from the body of each 2-D block.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Marginals {
+    /// Reduction applied to each row, each column and the whole block.
+    pub reduction: Reduction,
+    /// Number of decimal places shown, unless the values shown together are all integral.
+    pub precision: usize,
+}
+
+impl Marginals {
+    /// Construct a new `Marginals` instance which applies the given reduction.
+    #[must_use]
+    #[inline]
+    pub const fn new(reduction: Reduction) -> Self {
+        Self { reduction, precision: 2 }
+    }
+
+    /// Set the number of decimal places shown, unless the values shown together are all integral.
+    #[must_use]
+    #[inline]
+    pub const fn with_precision(mut self, precision: usize) -> Self {
+        self.precision = precision;
+        self
+    }
+}
+
+impl Default for Marginals {
+    #[inline]
+    fn default() -> Self {
+        Self::new(Reduction::Sum)
+    }
+}
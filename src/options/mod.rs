@@ -0,0 +1,27 @@
+//! ## Options
+//!
+//! This module contains the optional layout settings which can be attached to a `DisplayArray`.
+
+mod axis_map;
+mod data_bars;
+mod marginals;
+mod mosaic;
+mod ortho_slices;
+mod overview;
+mod reduction;
+mod rulers;
+mod slice_headers;
+mod window;
+mod wrap;
+
+pub use axis_map::AxisMap;
+pub use data_bars::{BarPlacement, DataBars};
+pub use marginals::Marginals;
+pub use mosaic::Mosaic;
+pub use ortho_slices::OrthoSlices;
+pub use overview::Overview;
+pub use reduction::Reduction;
+pub use rulers::Rulers;
+pub use slice_headers::{SliceHeaders, SliceStyle};
+pub use window::Window;
+pub use wrap::Wrap;
@@ -0,0 +1,65 @@
+//! ### `Mosaic`
+//!
+//! This module contains the `Mosaic` option, which tiles the 2-D blocks of higher-dimensional arrays into a grid.
+
+/// Grid layout for the 2-D blocks of 3-D and higher arrays.
+///
+/// The first page axis runs across the grid, and any remaining page axes run down it.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Mosaic {
+    /// Maximum number of tiles placed side by side before wrapping onto a new row of tiles.
+    pub wrap: Option<usize>,
+    /// Number of spaces placed between neighbouring tiles.
+    pub gutter: usize,
+    /// Number of blank lines placed between rows of tiles.
+    pub spacing: usize,
+    /// Whether each tile is headed by its position.
+    pub labels: bool,
+}
+
+impl Mosaic {
+    /// Construct a new `Mosaic` instance.
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            wrap: None,
+            gutter: 3,
+            spacing: 1,
+            labels: false,
+        }
+    }
+
+    /// Wrap onto a new row of tiles after the given number of tiles.
+    #[must_use]
+    #[inline]
+    pub const fn with_wrap(mut self, wrap: usize) -> Self {
+        self.wrap = Some(if wrap == 0 { 1 } else { wrap });
+        self
+    }
+
+    /// Set the number of spaces between neighbouring tiles, and blank lines between rows of tiles.
+    #[must_use]
+    #[inline]
+    pub const fn with_gutters(mut self, gutter: usize, spacing: usize) -> Self {
+        self.gutter = gutter;
+        self.spacing = spacing;
+        self
+    }
+
+    /// Set whether each tile is headed by its position.
+    #[must_use]
+    #[inline]
+    pub const fn with_labels(mut self, labels: bool) -> Self {
+        self.labels = labels;
+        self
+    }
+}
+
+impl Default for Mosaic {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,61 @@
+//! ### `OrthoSlices`
+//!
+//! This module contains the `OrthoSlices` option, which shows the three orthogonal planes through a point of a volume.
+
+/// Planes through a point of a 3-D array, one normal to each axis, shown side by side.
+///
+/// Each plane is headed by its position, and crosshairs mark the row and column where the other two planes cross it.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct OrthoSlices {
+    /// Point through which the planes pass. The middle of the displayed region is used if unset.
+    pub point: Option<[usize; 3]>,
+    /// Whether the row and column where the other planes cross each plane are marked.
+    pub crosshairs: bool,
+    /// Number of spaces placed between neighbouring planes.
+    pub gutter: usize,
+}
+
+impl OrthoSlices {
+    /// Construct a new `OrthoSlices` instance through the middle of the displayed region, with crosshairs.
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            point: None,
+            crosshairs: true,
+            gutter: 3,
+        }
+    }
+
+    /// Pass the planes through the given point, in original coordinates.
+    #[must_use]
+    #[inline]
+    pub const fn with_point(mut self, point: [usize; 3]) -> Self {
+        self.point = Some(point);
+        self
+    }
+
+    /// Set whether the row and column where the other planes cross each plane are marked.
+    #[must_use]
+    #[inline]
+    pub const fn with_crosshairs(mut self, crosshairs: bool) -> Self {
+        self.crosshairs = crosshairs;
+        self
+    }
+
+    /// Set the number of spaces placed between neighbouring planes.
+    #[must_use]
+    #[inline]
+    pub const fn with_gutter(mut self, gutter: usize) -> Self {
+        self.gutter = gutter;
+        self
+    }
+}
+
+impl Default for OrthoSlices {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,64 @@
+//! ### `Overview`
+//!
+//! This module contains the `Overview` option, which shrinks large arrays to a target size by pooling blocks of elements.
+
+use crate::Reduction;
+
+/// Downsampled display of large arrays, produced by reducing blocks of neighbouring elements to a single value.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Overview {
+    /// Maximum number of columns displayed.
+    pub width: usize,
+    /// Maximum number of rows displayed.
+    pub height: usize,
+    /// Reduction applied to each block.
+    pub reduction: Reduction,
+    /// Number of decimal places shown, unless the values shown together are all integral.
+    pub precision: usize,
+}
+
+impl Overview {
+    /// Construct a new `Overview` instance which averages blocks to fit within the given number of columns and rows.
+    #[must_use]
+    #[inline]
+    pub const fn new(width: usize, height: usize) -> Self {
+        Self {
+            width: if width == 0 { 1 } else { width },
+            height: if height == 0 { 1 } else { height },
+            reduction: Reduction::Mean,
+            precision: 2,
+        }
+    }
+
+    /// Set the reduction applied to each block.
+    #[must_use]
+    #[inline]
+    pub const fn with_reduction(mut self, reduction: Reduction) -> Self {
+        self.reduction = reduction;
+        self
+    }
+
+    /// Set the number of decimal places shown, unless the values shown together are all integral.
+    #[must_use]
+    #[inline]
+    pub const fn with_precision(mut self, precision: usize) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    /// Number of rows and columns pooled into each block, for an array of the given number of rows and columns.
+    #[must_use]
+    #[inline]
+    pub const fn factors(&self, nrows: usize, ncols: usize) -> (usize, usize) {
+        (
+            max_one(nrows.div_ceil(max_one(self.height))),
+            max_one(ncols.div_ceil(max_one(self.width))),
+        )
+    }
+}
+
+/// Raise zero to one.
+const fn max_one(n: usize) -> usize {
+    if n == 0 { 1 } else { n }
+}
@@ -0,0 +1,67 @@
+//! ### `Reduction`
+//!
+//! This module contains the `Reduction` type, which combines a group of values into a single value.
+
+use std::ptr::fn_addr_eq;
+
+use crate::Numeric as _;
+
+/// Ways of combining a group of values into a single value.
+#[derive(Debug, Copy, Clone, Eq)]
+#[non_exhaustive]
+pub enum Reduction {
+    /// Total of the values.
+    Sum,
+    /// Arithmetic mean of the values.
+    Mean,
+    /// Smallest value.
+    Min,
+    /// Largest value.
+    Max,
+    /// One if any value is non-zero, otherwise zero.
+    Any,
+    /// User-supplied function, such as a non-capturing closure.
+    Custom(fn(&[f64]) -> f64),
+}
+
+impl Reduction {
+    /// Lower-case name of the reduction.
+    #[must_use]
+    #[inline]
+    pub const fn name(&self) -> &'static str {
+        match *self {
+            Self::Sum => "sum",
+            Self::Mean => "mean",
+            Self::Min => "min",
+            Self::Max => "max",
+            Self::Any => "any",
+            Self::Custom(_) => "custom",
+        }
+    }
+
+    /// Combine the given values. Empty groups reduce to `NaN`, except for `Sum` and `Any` which reduce to zero.
+    #[must_use]
+    #[inline]
+    pub fn reduce(&self, values: &[f64]) -> f64 {
+        match *self {
+            Self::Sum => values.iter().sum(),
+            Self::Mean => values.iter().sum::<f64>() / values.len().to_f64(),
+            Self::Min => values.iter().copied().reduce(f64::min).unwrap_or(f64::NAN),
+            Self::Max => values.iter().copied().reduce(f64::max).unwrap_or(f64::NAN),
+            Self::Any => f64::from(values.iter().any(|&value| value != 0.0)),
+            Self::Custom(function) => function(values),
+        }
+    }
+}
+
+impl PartialEq for Reduction {
+    /// Custom reductions are equal if they refer to the same function address, which is not guaranteed to be unique.
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        match (*self, *other) {
+            (Self::Custom(a), Self::Custom(b)) => fn_addr_eq(a, b),
+            (Self::Custom(_), _) | (_, Self::Custom(_)) => false,
+            _ => self.name() == other.name(),
+        }
+    }
+}
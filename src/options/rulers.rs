@@ -0,0 +1,56 @@
+//! ### `Rulers`
+//!
+//! This module contains the `Rulers` option, which labels the row and column indices of displayed arrays.
+
+/// Index labels drawn along the top and left edges of each displayed 2-D block.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Rulers {
+    /// Interval between labelled indices.
+    pub step: usize,
+    /// Whether tick marks are drawn beneath the column labels.
+    pub ticks: bool,
+}
+
+impl Rulers {
+    /// Construct a new `Rulers` instance which labels every index.
+    ///
+    /// Where column labels would collide, they are thinned to every 2nd, 5th, 10th, 20th, ... index, above tick marks.
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self { step: 1, ticks: false }
+    }
+
+    /// Construct a new `Rulers` instance which labels every `step`th index and marks the others with ticks.
+    #[must_use]
+    #[inline]
+    pub const fn every(step: usize) -> Self {
+        Self {
+            step: if step == 0 { 1 } else { step },
+            ticks: true,
+        }
+    }
+
+    /// Set whether tick marks are drawn beneath the column labels.
+    #[must_use]
+    #[inline]
+    pub const fn with_ticks(mut self, ticks: bool) -> Self {
+        self.ticks = ticks;
+        self
+    }
+
+    /// Check if the given index should be labelled.
+    #[must_use]
+    #[inline]
+    pub const fn is_labelled(&self, index: usize) -> bool {
+        index.is_multiple_of(if self.step == 0 { 1 } else { self.step })
+    }
+}
+
+impl Default for Rulers {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
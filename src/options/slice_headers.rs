@@ -0,0 +1,94 @@
+//! ### `SliceHeaders`
+//!
+//! This module contains the `SliceHeaders` option, which labels each 2-D block of a higher-dimensional array with its position.
+
+/// Styles in which the position of a 2-D block can be written.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SliceStyle {
+    /// Index every axis, using `:` for the axes of the block, such as `[1, 0, :, :]`.
+    Index,
+    /// Assign a value to each fixed axis by name, such as `t=1, z=0`.
+    Named,
+}
+
+/// Header line placed above each 2-D block of a higher-dimensional array.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct SliceHeaders {
+    /// Style in which the position is written.
+    pub style: SliceStyle,
+    /// Names of the axes, in order. Unnamed axes are called `axisN`.
+    pub names: Vec<String>,
+}
+
+impl SliceHeaders {
+    /// Construct a new `SliceHeaders` instance using the `Index` style.
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            style: SliceStyle::Index,
+            names: Vec::new(),
+        }
+    }
+
+    /// Construct a new `SliceHeaders` instance using the `Named` style with the given axis names.
+    #[must_use]
+    #[inline]
+    pub fn named<I, N>(names: I) -> Self
+    where
+        I: IntoIterator<Item = N>,
+        N: Into<String>,
+    {
+        Self {
+            style: SliceStyle::Named,
+            names: names.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// Set the style in which the position is written.
+    #[must_use]
+    #[inline]
+    pub const fn with_style(mut self, style: SliceStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Name of the given axis.
+    #[must_use]
+    #[inline]
+    pub fn name(&self, axis: usize) -> String {
+        self.names.get(axis).cloned().unwrap_or_else(|| format!("axis{axis}"))
+    }
+
+    /// Write the header for a block at the given position, where `None` marks the axes spanned by the block.
+    #[must_use]
+    #[inline]
+    pub fn label(&self, position: &[Option<usize>]) -> String {
+        match self.style {
+            SliceStyle::Index => {
+                let indices: Vec<String> = position
+                    .iter()
+                    .map(|index| index.map_or_else(|| ":".to_owned(), |i| i.to_string()))
+                    .collect();
+                format!("[{}]", indices.join(", "))
+            }
+            SliceStyle::Named => {
+                let assignments: Vec<String> = position
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(axis, index)| index.map(|i| format!("{}={i}", self.name(axis))))
+                    .collect();
+                assignments.join(", ")
+            }
+        }
+    }
+}
+
+impl Default for SliceHeaders {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
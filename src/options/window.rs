@@ -0,0 +1,38 @@
+//! ### `Window`
+//!
+//! This module contains the `Window` option, which restricts the display to a box-shaped region of the arrays.
+
+use std::ops::Range;
+
+/// Box-shaped region of the arrays to display, given as a range of indices along each axis.
+///
+/// Axes without a range are displayed in full, and ranges extending past the end of an axis are clipped to it.
+/// Rows and columns outside the region are marked with ellipses, and pages outside it are counted before the first
+/// displayed page and after the last.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Window {
+    /// Range of indices displayed along each axis, in order.
+    pub ranges: Vec<Range<usize>>,
+}
+
+impl Window {
+    /// Construct a new `Window` instance from the range of indices to display along each axis.
+    #[must_use]
+    #[inline]
+    pub fn new<I: IntoIterator<Item = Range<usize>>>(ranges: I) -> Self {
+        Self {
+            ranges: ranges.into_iter().collect(),
+        }
+    }
+
+    /// Range of indices displayed along the given axis of the given length.
+    #[must_use]
+    #[inline]
+    pub fn range(&self, axis: usize, len: usize) -> Range<usize> {
+        self.ranges.get(axis).map_or(0..len, |range| {
+            let start = range.start.min(len);
+            start..range.end.clamp(start, len)
+        })
+    }
+}
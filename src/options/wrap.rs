@@ -0,0 +1,44 @@
+//! ### `Wrap`
+//!
+//! This module contains the `Wrap` option, which splits wide arrays into blocks of columns that fit a line width.
+
+use std::env;
+
+/// Line width beyond which the columns of each 2-D block are split into separate blocks, headed by the columns they hold.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Wrap {
+    /// Maximum number of characters in each line.
+    pub width: usize,
+}
+
+impl Wrap {
+    /// Line width used when the terminal width cannot be detected.
+    pub const DEFAULT_WIDTH: usize = 80;
+
+    /// Construct a new `Wrap` instance with the given line width.
+    #[must_use]
+    #[inline]
+    pub const fn new(width: usize) -> Self {
+        Self { width }
+    }
+
+    /// Construct a new `Wrap` instance using the terminal width from the `COLUMNS` environment variable,
+    /// or `DEFAULT_WIDTH` if it is not set.
+    #[must_use]
+    #[inline]
+    pub fn detect() -> Self {
+        let width = env::var("COLUMNS")
+            .ok()
+            .and_then(|columns| columns.trim().parse().ok())
+            .unwrap_or(Self::DEFAULT_WIDTH);
+        Self { width }
+    }
+}
+
+impl Default for Wrap {
+    #[inline]
+    fn default() -> Self {
+        Self::detect()
+    }
+}